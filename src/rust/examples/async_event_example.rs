@@ -0,0 +1,62 @@
+use norm::{EventType, Instance, MulticastExt, Result, multicast};
+use std::env;
+use std::pin::Pin;
+
+// Demonstrates driving a NORM receiver from an async task via
+// `Instance::event_stream`, instead of dedicating a thread to
+// `Instance::events`. Requires the `tokio` feature.
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args: Vec<String> = env::args().collect();
+    let (address, port) = if args.len() > 2 {
+        (args[1].as_str(), args[2].parse::<u16>().unwrap_or(6003))
+    } else {
+        println!("Using default multicast address 224.1.2.3:6003");
+        println!("Usage: {} <address> <port>", args[0]);
+        ("224.1.2.3", 6003)
+    };
+
+    let instance = Instance::new(false)?;
+    let session = instance.create_session(address, port, 2)?;
+
+    let mc_config = multicast!(address, port, {
+        ttl: 64,
+        loopback: true,
+    });
+    session.with_multicast(&mc_config)?;
+    session.start_receiver(1024 * 1024)?;
+
+    println!("NORM receiver started on {}:{}", address, port);
+    println!("Waiting for data...");
+
+    let mut stream = instance
+        .event_stream()
+        .map_err(|e| norm::Error::OperationFailed(format!("failed to register event stream: {e}")))?;
+
+    loop {
+        use futures_core::Stream;
+        let Some(event) = std::future::poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await else {
+            println!("Event stream ended");
+            break;
+        };
+
+        match event.event_type {
+            EventType::RemoteSenderNew => {
+                println!("New sender connected");
+            }
+            EventType::RxObjectCompleted => {
+                let object = norm::Object::from_handle_unowned(event.object);
+                if let Ok(data) = object.access_data() {
+                    println!("Received data: {:?}", String::from_utf8_lossy(data));
+                }
+                break;
+            }
+            _ => {}
+        }
+    }
+
+    session.stop_receiver();
+    println!("Async event example completed");
+
+    Ok(())
+}
@@ -33,8 +33,12 @@ fn main() -> Result<()> {
     });
     session.with_multicast(&mc_config)?;
 
-    // Set the transmission rate (in bits per second)
-    session.set_tx_rate(25_000_000.0); // 25 Mbps
+    // Let NORM's congestion control converge on a TCP-friendly rate between
+    // these bounds instead of blasting at a fixed bitrate, and treat ECN
+    // marks as an early congestion signal.
+    session.set_tx_rate_bounds(64_000.0, 25_000_000.0);
+    session.set_congestion_control(true, true);
+    session.set_ecn_support(true, false, false);
 
     // Start the sender
     let session_id = rand::random::<u16>();
@@ -66,6 +70,9 @@ fn main() -> Result<()> {
             EventType::RemoteSenderNew => {
                 println!("Receiver joined");
             }
+            EventType::GrttUpdated => {
+                println!("GRTT updated: grtt={:.4}s tx_rate={:.0}bps", session.grtt_estimate(), session.tx_rate());
+            }
             _ => {
                 // Ignore other events
             }
@@ -1,4 +1,5 @@
-use norm::{Instance, Session, multicast, MulticastExt, EventType, ObjectType, FlushMode, Result};
+use norm::{Instance, Session, multicast, MulticastExt, EventType, ObjectType, Result};
+use std::io::{Read, Write};
 use std::time::Duration;
 use std::{thread, env};
 use std::str;
@@ -57,44 +58,41 @@ fn run_sender(address: &str, port: u16) -> Result<()> {
     // Open a stream with 64KB buffer
     let stream_buffer_size = 64 * 1024;
     let info = b"Example stream";
-    let stream = session.stream_open(stream_buffer_size, Some(info))?;
+    let mut stream = session.stream_open(stream_buffer_size, Some(info))?;
 
     println!("Stream opened, sending messages...");
 
-    // Send 10 messages through the stream
+    // Send 10 messages through the stream, via the `std::io::Write` impl on `Object`
     for i in 1..=10 {
         let message = format!("Stream message #{}", i);
         println!("Sending: {}", message);
 
-        // Write message to stream
-        let bytes_written = unsafe {
-            // Using the raw API here for simplicity
-            // In a real implementation, you'd want to create a safer wrapper
-            norm_sys::NormStreamWrite(
-                stream.handle(),
-                message.as_ptr() as *const i8,
-                message.len() as u32,
-            )
-        };
-
-        if bytes_written < message.len() as u32 {
-            println!("Warning: Only wrote {} of {} bytes", bytes_written, message.len());
+        // `write` honors partial writes (the send buffer may be full), so
+        // retry until the whole message is buffered.
+        let mut sent = 0;
+        while sent < message.len() {
+            match stream.write(&message.as_bytes()[sent..]) {
+                Ok(n) => sent += n,
+                Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                    thread::sleep(Duration::from_millis(10));
+                }
+                Err(e) => {
+                    eprintln!("Stream write error: {e}");
+                    break;
+                }
+            }
         }
 
         // Mark end of message and flush passively
-        unsafe {
-            norm_sys::NormStreamMarkEom(stream.handle());
-            norm_sys::NormStreamFlush(stream.handle(), false, norm_sys::NormFlushMode_NORM_FLUSH_PASSIVE);
-        }
+        stream.stream_mark_eom()?;
+        stream.flush().ok();
 
         // Small delay between messages
         thread::sleep(Duration::from_millis(500));
     }
 
     // Close the stream gracefully
-    unsafe {
-        norm_sys::NormStreamClose(stream.handle(), true);
-    }
+    stream.stream_close(true)?;
 
     println!("Stream closed, waiting for transmission to complete...");
 
@@ -155,25 +153,25 @@ fn run_receiver(address: &str, port: u16) -> Result<()> {
                 // Stream data is available to read
                 if let Some(stream) = current_stream {
                     if event.object == stream {
-                        // Read from the stream
+                        // Read from the stream via the `std::io::Read` impl on `Object`
+                        let mut stream_obj = norm::Object::from_handle_unowned(stream);
                         let mut buffer = vec![0u8; 1024];
-                        let mut bytes_read = 0u32;
-
-                        let success = unsafe {
-                            norm_sys::NormStreamRead(
-                                stream,
-                                buffer.as_mut_ptr() as *mut i8,
-                                &mut bytes_read as *mut u32,
-                            )
-                        };
-
-                        if success && bytes_read > 0 {
-                            buffer.truncate(bytes_read as usize);
-                            println!("Received: {}", String::from_utf8_lossy(&buffer));
-                            message_count += 1;
-
-                            // Reset timeout counter when we get data
-                            timeout_count = 0;
+
+                        match stream_obj.read(&mut buffer) {
+                            Ok(bytes_read) => {
+                                buffer.truncate(bytes_read);
+                                println!("Received: {}", String::from_utf8_lossy(&buffer));
+                                message_count += 1;
+
+                                // Reset timeout counter when we get data
+                                timeout_count = 0;
+                            }
+                            Err(e) if e.kind() == std::io::ErrorKind::WouldBlock => {
+                                // No data currently available; wait for the next event
+                            }
+                            Err(e) => {
+                                eprintln!("Stream read error: {e}");
+                            }
                         }
                     }
                 }
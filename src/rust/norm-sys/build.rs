@@ -4,62 +4,101 @@ use std::path::PathBuf;
 fn main() {
     // Tell cargo to invalidate the built crate whenever the wrapper.h changes
     println!("cargo:rerun-if-changed=wrapper.h");
+    println!("cargo:rerun-if-env-changed=NORM_INCLUDE_DIR");
+    println!("cargo:rerun-if-env-changed=NORM_LIB_DIR");
+    println!("cargo:rerun-if-env-changed=NORM_SYSROOT");
 
-    // First, try to find the NORM header directory from an environment variable
     let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
-    let norm_include_dir = env::var("NORM_INCLUDE_DIR").unwrap_or_else(|_| {
-        // Default to looking for include files in the main repository directory
-        format!("{}/../../../include", manifest_dir)
-    });
-
-    // Tell cargo to look for libnorm in the build directory
-    let lib_path = format!("{}/../../../build", manifest_dir);
-
-    println!("cargo:rustc-link-search={}", lib_path);
-    println!("cargo:rustc-link-lib=norm");
-
-    // Add rpath so the dynamic library can be found at runtime
-    // This is especially important for development and testing
-    #[cfg(target_os = "macos")]
-    println!("cargo:rustc-link-arg=-Wl,-rpath,{}", lib_path);
-    #[cfg(target_os = "linux")]
-    println!("cargo:rustc-link-arg=-Wl,-rpath,{}", lib_path);
-
-    // Add linkage to ProtoKit if needed
-    let protolib_path = format!("{}/protolib", lib_path);
-    println!("cargo:rustc-link-search={}", protolib_path);
-    println!("cargo:rustc-link-lib=protokit");
-
-    // Add rpath for protolib as well
-    #[cfg(target_os = "macos")]
-    println!("cargo:rustc-link-arg=-Wl,-rpath,{}", protolib_path);
-    #[cfg(target_os = "linux")]
-    println!("cargo:rustc-link-arg=-Wl,-rpath,{}", protolib_path);
-
-    // Link pthread on Unix
-    if cfg!(unix) {
-        println!("cargo:rustc-link-lib=pthread");
-    }
 
-    // On macOS, we might need additional system libraries
-    if cfg!(target_os = "macos") {
-        println!("cargo:rustc-link-lib=resolv");
-    }
+    // `CARGO_CFG_TARGET_*` always reflects the *target* triple, even when
+    // cross-compiling, unlike `cfg!(...)` which reflects the host this build
+    // script itself was compiled for -- every `#[cfg]`-equivalent check below
+    // reads these instead so a cross build doesn't pick up host-only flags.
+    let target_os = env::var("CARGO_CFG_TARGET_OS").unwrap_or_default();
+    let target_family = env::var("CARGO_CFG_TARGET_FAMILY").unwrap_or_default();
+    let target = env::var("TARGET").unwrap_or_default();
+    let host = env::var("HOST").unwrap_or_default();
+    let cross_compiling = !target.is_empty() && target != host;
+
+    // With the `dlopen` feature, libnorm/libprotokit are resolved at runtime
+    // via `norm_sys::dlopen` instead, so skip the link-time dependency
+    // entirely -- this lets the crate build without those shared libraries
+    // present at compile time.
+    let static_link = env::var("CARGO_FEATURE_DLOPEN").is_err();
+
+    // Discover the NORM headers/libraries in priority order:
+    //   1. pkg-config, for a system-packaged NORM (skipped when
+    //      cross-compiling, since pkg-config would report host paths)
+    //   2. NORM_INCLUDE_DIR / NORM_LIB_DIR env vars, for a cross-compilation
+    //      sysroot or any other non-standard layout
+    //   3. the in-tree relative default, for building against this
+    //      repository's own `build/` output
+    let pkg_config_lib = if static_link && !cross_compiling {
+        pkg_config::Config::new().probe("norm").ok()
+    } else {
+        None
+    };
+
+    let norm_include_dir = pkg_config_lib
+        .as_ref()
+        .and_then(|lib| lib.include_paths.first())
+        .map(|p| p.display().to_string())
+        .or_else(|| env::var("NORM_INCLUDE_DIR").ok())
+        .unwrap_or_else(|| format!("{manifest_dir}/../../../include"));
+
+    if static_link {
+        if pkg_config_lib.is_some() {
+            // pkg-config already emitted the link-search/link-lib directives
+            // for "norm". ProtoKit isn't always packaged separately; if
+            // pkg-config can't find it, assume it's bundled with the probed
+            // NORM library rather than treating that as fatal.
+            let _ = pkg_config::Config::new().probe("protokit");
+        } else {
+            let lib_dir = env::var("NORM_LIB_DIR").unwrap_or_else(|_| format!("{manifest_dir}/../../../build"));
+
+            println!("cargo:rustc-link-search={lib_dir}");
+            println!("cargo:rustc-link-lib=norm");
+
+            // rpath only makes sense when the binary will actually run on
+            // this host, i.e. we are not cross-compiling for another target.
+            if !cross_compiling && matches!(target_os.as_str(), "macos" | "linux") {
+                println!("cargo:rustc-link-arg=-Wl,-rpath,{lib_dir}");
+            }
 
-    // On Solaris, add these libraries
-    if cfg!(target_os = "solaris") {
-        println!("cargo:rustc-link-lib=nsl");
-        println!("cargo:rustc-link-lib=socket");
-        println!("cargo:rustc-link-lib=resolv");
+            let protolib_dir = format!("{lib_dir}/protolib");
+            println!("cargo:rustc-link-search={protolib_dir}");
+            println!("cargo:rustc-link-lib=protokit");
+
+            if !cross_compiling && matches!(target_os.as_str(), "macos" | "linux") {
+                println!("cargo:rustc-link-arg=-Wl,-rpath,{protolib_dir}");
+            }
+        }
+
+        // Link pthread on target Unix platforms
+        if target_family == "unix" {
+            println!("cargo:rustc-link-lib=pthread");
+        }
+
+        // On macOS, we might need additional system libraries
+        if target_os == "macos" {
+            println!("cargo:rustc-link-lib=resolv");
+        }
+
+        // On Solaris, add these libraries
+        if target_os == "solaris" {
+            println!("cargo:rustc-link-lib=nsl");
+            println!("cargo:rustc-link-lib=socket");
+            println!("cargo:rustc-link-lib=resolv");
+        }
     }
 
     // Generate bindings for the NORM API
-    let bindings = bindgen::Builder::default()
+    let mut builder = bindgen::Builder::default()
         .header("wrapper.h")
         // IMPORTANT: Tell clang to treat this as C++ code
         .clang_arg("-x")
         .clang_arg("c++")
-        .clang_arg(format!("-I{}", norm_include_dir))
+        .clang_arg(format!("-I{norm_include_dir}"))
         // Whitelist NORM functions, types, and constants
         .allowlist_function("Norm.*")
         .allowlist_type("Norm.*")
@@ -72,13 +111,21 @@ fn main() {
         // Generate documentation from C comments
         .generate_comments(true)
         // Parse callbacks for cargo build info (updated to new API)
-        .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()))
-        .generate()
-        .expect("Unable to generate NORM bindings");
+        .parse_callbacks(Box::new(bindgen::CargoCallbacks::new()));
+
+    if cross_compiling {
+        builder = builder.clang_arg(format!("--target={target}"));
+
+        if let Ok(sysroot) = env::var("NORM_SYSROOT") {
+            builder = builder.clang_arg(format!("--sysroot={sysroot}"));
+        }
+    }
+
+    let bindings = builder.generate().expect("Unable to generate NORM bindings");
 
     // Write the bindings to the $OUT_DIR/bindings.rs file
     let out_path = PathBuf::from(env::var("OUT_DIR").unwrap());
     bindings
         .write_to_file(out_path.join("bindings.rs"))
         .expect("Couldn't write NORM bindings!");
-}
\ No newline at end of file
+}
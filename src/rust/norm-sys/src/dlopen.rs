@@ -0,0 +1,140 @@
+//! Runtime dynamic loading of libnorm via `libloading`, behind the `dlopen` feature.
+//!
+//! With this feature on, `build.rs` skips the `cargo:rustc-link-lib` directives
+//! for `norm`/`protokit`, so the crate builds even when those shared libraries
+//! aren't present at link time. [`NormLibrary::load`] opens the library at
+//! runtime instead and resolves each entry point once into a struct of typed
+//! function pointers, keeping the `libloading::Library` alive alongside them so
+//! the pointers can never outlive the mapping they came from. [`library()`]
+//! caches the result behind a `OnceLock`, resolving on first use.
+//!
+//! Four entry points are resolved here (`NormCreateInstance`,
+//! `NormDestroyInstance`, `NormGetNextEvent`, `NormGetDescriptor`); `norm::Instance`
+//! routes its creation, destruction, event polling and descriptor lookup
+//! through them. Every other wrapper in the `norm` crate (the rest of
+//! `Instance`, and `session`, `object`) still calls its `Norm*` function as a
+//! direct `extern "C"` call, which `build.rs` does not link with this feature
+//! on.
+
+use crate::{NormEvent, NormInstanceHandle};
+use libloading::{Library, Symbol};
+use std::env;
+use std::os::raw::c_int;
+use std::sync::OnceLock;
+
+/// Environment variable overriding the path to the NORM shared library.
+pub const NORM_LIB_PATH_VAR: &str = "NORM_LIB_PATH";
+
+#[cfg(target_os = "linux")]
+const DEFAULT_LIB_NAMES: &[&str] = &["libnorm.so"];
+#[cfg(target_os = "macos")]
+const DEFAULT_LIB_NAMES: &[&str] = &["libnorm.dylib"];
+#[cfg(target_os = "windows")]
+const DEFAULT_LIB_NAMES: &[&str] = &["norm.dll"];
+
+/// The NORM shared library could not be located or a required symbol was missing.
+#[derive(Debug, Clone)]
+pub struct LoadError(pub String);
+
+impl std::fmt::Display for LoadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for LoadError {}
+
+/// Resolved `Norm*` entry points, mirroring the bindgen signatures they stand in for.
+///
+/// Kept alive for as long as the function pointers are: the `Library` field
+/// is never read, only held, so the mapping it owns can't be unloaded out
+/// from under a call through one of the pointers.
+pub struct NormLibrary {
+    _library: Library,
+    pub norm_create_instance: unsafe extern "C" fn(bool) -> NormInstanceHandle,
+    pub norm_destroy_instance: unsafe extern "C" fn(NormInstanceHandle),
+    pub norm_get_next_event: unsafe extern "C" fn(NormInstanceHandle, *mut NormEvent, bool) -> bool,
+    pub norm_get_descriptor: unsafe extern "C" fn(NormInstanceHandle) -> c_int,
+}
+
+impl NormLibrary {
+    /// Open `path` (or, if `None`, the platform-default library name) and
+    /// resolve every entry point this loader knows about.
+    ///
+    /// # Safety
+    /// The caller must ensure the resolved symbols actually have the
+    /// signatures declared above; a mismatched shared library would make
+    /// every call through them undefined behavior.
+    unsafe fn load(path: Option<&str>) -> Result<Self, LoadError> {
+        let library = match path {
+            Some(path) => Library::new(path)
+                .map_err(|e| LoadError(format!("failed to load NORM library at {path}: {e}")))?,
+            None => Self::load_default()?,
+        };
+
+        macro_rules! resolve {
+            ($name:literal) => {{
+                let symbol: Symbol<'_, *const ()> = library
+                    .get(concat!($name, "\0").as_bytes())
+                    .map_err(|e| LoadError(format!("symbol {} not found: {e}", $name)))?;
+                std::mem::transmute_copy(&*symbol)
+            }};
+        }
+
+        Ok(NormLibrary {
+            norm_create_instance: resolve!("NormCreateInstance"),
+            norm_destroy_instance: resolve!("NormDestroyInstance"),
+            norm_get_next_event: resolve!("NormGetNextEvent"),
+            norm_get_descriptor: resolve!("NormGetDescriptor"),
+            _library: library,
+        })
+    }
+
+    fn load_default() -> Result<Library, LoadError> {
+        let mut last_error = None;
+        for name in DEFAULT_LIB_NAMES {
+            match unsafe { Library::new(name) } {
+                Ok(library) => return Ok(library),
+                Err(e) => last_error = Some(e),
+            }
+        }
+
+        Err(LoadError(format!(
+            "could not find any of {DEFAULT_LIB_NAMES:?} on the library search path ({})",
+            last_error.map(|e| e.to_string()).unwrap_or_default()
+        )))
+    }
+}
+
+static LIBRARY: OnceLock<(Option<String>, Result<NormLibrary, LoadError>)> = OnceLock::new();
+
+/// Resolve (once) and return the dynamically-loaded NORM library.
+///
+/// The path checked is, in order: `path_override`, the `NORM_LIB_PATH`
+/// environment variable, then the platform-default library filename(s) on
+/// the system's usual search path.
+///
+/// The library is only ever loaded once, on the first call -- a later call
+/// with a different `path_override` does not reload it. Rather than
+/// silently handing back whatever was loaded first, such a call returns an
+/// error naming both paths, so a mismatched `Instance::load_from` doesn't
+/// go unnoticed.
+pub fn library(path_override: Option<&str>) -> Result<&'static NormLibrary, LoadError> {
+    let requested_path = path_override.map(str::to_string).or_else(|| env::var(NORM_LIB_PATH_VAR).ok());
+
+    let (loaded_path, result) = LIBRARY.get_or_init(|| {
+        let path = requested_path.clone();
+        (path, unsafe { NormLibrary::load(path.as_deref()) })
+    });
+
+    if let Some(requested) = path_override {
+        if loaded_path.as_deref() != Some(requested) {
+            return Err(LoadError(format!(
+                "NORM library already loaded from {}; cannot also load from {requested}",
+                loaded_path.as_deref().unwrap_or("the platform default search path"),
+            )));
+        }
+    }
+
+    result.as_ref().map_err(Clone::clone)
+}
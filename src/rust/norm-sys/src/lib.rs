@@ -12,6 +12,11 @@
 // The bindings will be included here by the build script
 include!(concat!(env!("OUT_DIR"), "/bindings.rs"));
 
+/// Runtime dynamic loading of the NORM shared library, as an alternative to
+/// the link-time dependency `build.rs` otherwise sets up.
+#[cfg(feature = "dlopen")]
+pub mod dlopen;
+
 #[cfg(test)]
 mod tests {
     use super::*;
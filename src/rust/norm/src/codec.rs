@@ -0,0 +1,139 @@
+//! Length-delimited, typed message framing over a NORM stream [`Object`], behind the `codec` feature.
+//!
+//! Each message is `bincode`-encoded and sent as a 4-byte big-endian length
+//! prefix followed by the payload. [`FramedSender`] hides the prefixing and
+//! partial-write retry; [`FramedReceiver`] hides the read buffering needed to
+//! reassemble frames that a single `stream_read` may split (a length header
+//! straddling two reads) or coalesce (several frames in one read).
+
+use crate::error::{Error, Result};
+use crate::object::{Object, STREAM_VACANCY_POLL_INTERVAL};
+use crate::types::FlushMode;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::marker::PhantomData;
+
+const LENGTH_PREFIX_LEN: usize = 4;
+
+/// Default cap on a single frame's payload size, guarding against an
+/// unbounded allocation from a corrupt or malicious length header.
+pub const DEFAULT_MAX_FRAME_SIZE: usize = 16 * 1024 * 1024;
+
+/// Writes length-prefixed, `bincode`-encoded frames to a stream [`Object`].
+pub struct FramedSender<T> {
+    object: Object,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Serialize> FramedSender<T> {
+    /// Wrap a stream object opened for sending
+    pub fn new(object: Object) -> Self {
+        FramedSender { object, _marker: PhantomData }
+    }
+
+    /// Serialize and send one frame.
+    ///
+    /// Retries through NORM's buffer-full condition (a short `stream_write`)
+    /// until the whole frame is written, then passively flushes. Backs off
+    /// with a short sleep whenever the stream has no vacancy, rather than
+    /// busy-spinning writes against a full send buffer while waiting for it
+    /// to drain.
+    ///
+    /// # Errors
+    /// Returns an error if serialization or the underlying write fails.
+    pub fn send(&self, value: &T) -> Result<()> {
+        let payload = bincode::serialize(value).map_err(|e| Error::OperationFailed(e.to_string()))?;
+
+        let mut frame = Vec::with_capacity(LENGTH_PREFIX_LEN + payload.len());
+        frame.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        frame.extend_from_slice(&payload);
+
+        let mut sent = 0;
+        while sent < frame.len() {
+            if !self.object.stream_has_vacancy()? {
+                std::thread::sleep(STREAM_VACANCY_POLL_INTERVAL);
+                continue;
+            }
+
+            let n = self.object.stream_write(&frame[sent..])?;
+            if n == 0 {
+                std::thread::sleep(STREAM_VACANCY_POLL_INTERVAL);
+                continue;
+            }
+            sent += n;
+        }
+
+        self.object.stream_flush(false, FlushMode::Passive)
+    }
+}
+
+/// Reassembles length-prefixed, `bincode`-encoded frames read from a stream [`Object`].
+pub struct FramedReceiver<T> {
+    object: Object,
+    buffer: Vec<u8>,
+    max_frame_size: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T: DeserializeOwned> FramedReceiver<T> {
+    /// Wrap a stream object opened for receiving, capping a single frame's
+    /// payload at [`DEFAULT_MAX_FRAME_SIZE`]
+    pub fn new(object: Object) -> Self {
+        Self::with_max_frame_size(object, DEFAULT_MAX_FRAME_SIZE)
+    }
+
+    /// Wrap a stream object opened for receiving, capping a single frame's
+    /// payload at `max_frame_size` bytes
+    pub fn with_max_frame_size(object: Object, max_frame_size: usize) -> Self {
+        FramedReceiver { object, buffer: Vec::new(), max_frame_size, _marker: PhantomData }
+    }
+
+    /// Pull and decode every frame currently available.
+    ///
+    /// Call this on `RxObjectUpdated` for the wrapped object. Drains
+    /// `stream_read` until it reports no more data, decodes as many complete
+    /// frames as the accumulated bytes allow, and retains any trailing
+    /// partial frame -- including a length header split across reads -- for
+    /// the next call.
+    ///
+    /// # Errors
+    /// Returns an error if the underlying read fails, a length header
+    /// exceeds `max_frame_size`, or a frame fails to deserialize.
+    pub fn feed(&mut self) -> Result<Vec<T>> {
+        let mut chunk = [0u8; 4096];
+        loop {
+            let bytes_read = self.object.stream_read(&mut chunk)?;
+            if bytes_read == 0 {
+                break;
+            }
+            self.buffer.extend_from_slice(&chunk[..bytes_read]);
+        }
+
+        let mut frames = Vec::new();
+        loop {
+            if self.buffer.len() < LENGTH_PREFIX_LEN {
+                break;
+            }
+
+            let len = u32::from_be_bytes(self.buffer[..LENGTH_PREFIX_LEN].try_into().unwrap()) as usize;
+            if len > self.max_frame_size {
+                return Err(Error::OperationFailed(format!(
+                    "frame length {len} exceeds max_frame_size {}",
+                    self.max_frame_size
+                )));
+            }
+
+            if self.buffer.len() < LENGTH_PREFIX_LEN + len {
+                break;
+            }
+
+            let payload = &self.buffer[LENGTH_PREFIX_LEN..LENGTH_PREFIX_LEN + len];
+            let value = bincode::deserialize(payload).map_err(|e| Error::OperationFailed(e.to_string()))?;
+            frames.push(value);
+
+            self.buffer.drain(..LENGTH_PREFIX_LEN + len);
+        }
+
+        Ok(frames)
+    }
+}
@@ -0,0 +1,67 @@
+use std::ffi::CString;
+use std::fmt;
+
+/// Errors produced by the safe NORM wrappers.
+#[derive(Debug)]
+pub enum Error {
+    /// An argument was invalid for the operation being attempted
+    InvalidParameter,
+    /// A NORM call returned a null pointer where a valid one was expected
+    NullPointer,
+    /// A NORM call reported failure, with a short description of what was being attempted
+    OperationFailed(String),
+    /// A file-system operation failed, with a short description
+    FileError(String),
+    /// The NORM shared library could not be located or loaded at runtime
+    #[cfg(feature = "dlopen")]
+    LibraryNotFound(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidParameter => write!(f, "invalid parameter"),
+            Error::NullPointer => write!(f, "unexpected null pointer"),
+            Error::OperationFailed(msg) => write!(f, "{msg}"),
+            Error::FileError(msg) => write!(f, "{msg}"),
+            #[cfg(feature = "dlopen")]
+            Error::LibraryNotFound(msg) => write!(f, "{msg}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The result type returned by the safe NORM wrappers
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Turn a NORM boolean-returning call's result into a `Result<()>`
+///
+/// # Arguments
+/// * `success` - The boolean NORM returned
+/// * `message` - What to report if `success` was `false`
+pub(crate) fn bool_result(success: bool, message: &str) -> Result<()> {
+    if success {
+        Ok(())
+    } else {
+        Err(Error::OperationFailed(message.to_string()))
+    }
+}
+
+/// Reject a NORM handle if it equals the library's sentinel "invalid" value
+///
+/// # Arguments
+/// * `handle` - The handle NORM returned
+/// * `invalid` - The sentinel value that marks a failed creation (e.g. `NORM_INSTANCE_INVALID`)
+pub(crate) fn check_handle<H: PartialEq>(handle: H, invalid: H) -> Result<H> {
+    if handle == invalid {
+        Err(Error::NullPointer)
+    } else {
+        Ok(handle)
+    }
+}
+
+/// Convert a Rust string to a `CString` for passing to a NORM FFI call
+pub(crate) fn string_to_c_string(s: &str) -> Result<CString> {
+    CString::new(s).map_err(|_| Error::InvalidParameter)
+}
@@ -0,0 +1,35 @@
+use crate::types::EventType;
+use norm_sys::{NormEvent, NormNodeHandle, NormObjectHandle, NormSessionHandle};
+
+/// A single event read from an [`Instance`](crate::instance::Instance).
+///
+/// Mirrors the raw `NormEvent` the C API reports, decoded into the typed
+/// [`EventType`] plus the session/node/object handles it pertains to.
+/// `node`/`object` carry the NORM invalid-handle sentinels
+/// (`NORM_NODE_INVALID`/`NORM_OBJECT_INVALID`) when the event doesn't
+/// pertain to a remote node or object; callers check for that before
+/// wrapping the handle with `Node::from_handle_unowned`/
+/// `Object::from_handle_unowned`.
+#[derive(Debug, Clone, Copy)]
+pub struct Event {
+    /// The kind of event that occurred
+    pub event_type: EventType,
+    /// The session the event pertains to
+    pub session: NormSessionHandle,
+    /// The remote node the event pertains to, or `NORM_NODE_INVALID`
+    pub node: NormNodeHandle,
+    /// The object the event pertains to, or `NORM_OBJECT_INVALID`
+    pub object: NormObjectHandle,
+}
+
+impl Event {
+    /// Decode a raw `NormEvent` as reported by `NormGetNextEvent`.
+    pub(crate) fn from_raw(raw: NormEvent) -> Self {
+        Event {
+            event_type: raw.type_.into(),
+            session: raw.session,
+            node: raw.sender,
+            object: raw.object,
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! Self-describing, serde-serializable representation of NORM events, for
+//! forwarding across a process boundary (e.g. a control-plane IPC channel).
+//!
+//! The raw [`Event`] carries FFI handles that are only meaningful within the
+//! process that produced them, so it deliberately does not implement
+//! `Serialize`. [`EventRecord`] instead resolves those handles into the
+//! stable identifiers (`NodeId`, `ObjectTransportId`) that mean the same
+//! thing on both ends of the wire, and serializes `event_type` by its stable
+//! variant name (matching `EventType`'s `Display` impl) rather than the raw
+//! FFI discriminant, so the wire format survives bindgen value changes
+//! across NORM versions.
+
+use crate::event::Event;
+use crate::node::Node;
+use crate::object::Object;
+use crate::types::{EventType, NodeId, ObjectTransportId, SessionId};
+use norm_sys::{NORM_NODE_INVALID, NORM_OBJECT_INVALID};
+use serde::{Deserialize, Serialize};
+
+/// A flattened snapshot of a NORM [`Event`], suitable for serialization.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventRecord {
+    /// The kind of event that occurred
+    pub event_type: EventType,
+    /// The session the event was tagged under, e.g. by a `SessionManager`
+    pub session_id: SessionId,
+    /// The remote node involved, if the event carries one
+    pub node_id: Option<NodeId>,
+    /// The transport id of the object involved, if the event carries one
+    pub object_id: Option<ObjectTransportId>,
+}
+
+impl EventRecord {
+    /// Build a record from a raw event tagged with its originating session.
+    ///
+    /// The `node`/`object` handles on `event` are resolved to `None` when
+    /// they are the NORM invalid-handle sentinels, rather than carried
+    /// through as meaningless ids.
+    pub fn new(event: &Event, session_id: SessionId) -> Self {
+        let node_id = if event.node == NORM_NODE_INVALID {
+            None
+        } else {
+            Some(Node::from_handle_unowned(event.node).id())
+        };
+
+        let object_id = if event.object == NORM_OBJECT_INVALID {
+            None
+        } else {
+            Some(Object::from_handle_unowned(event.object).transport_id())
+        };
+
+        EventRecord { event_type: event.event_type, session_id, node_id, object_id }
+    }
+}
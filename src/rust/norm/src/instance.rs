@@ -29,7 +29,35 @@ impl Instance {
     /// # Returns
     /// A new NORM instance or an error if the instance could not be created
     pub fn new(priority_boost: bool) -> Result<Self> {
-        let handle = unsafe { NormCreateInstance(priority_boost) };
+        #[cfg(feature = "dlopen")]
+        {
+            Self::new_dynamic(None, priority_boost)
+        }
+        #[cfg(not(feature = "dlopen"))]
+        {
+            let handle = unsafe { NormCreateInstance(priority_boost) };
+            unsafe { check_handle(handle, NORM_INSTANCE_INVALID)? };
+            Ok(Instance { handle })
+        }
+    }
+
+    /// Create a new NORM instance, loading libnorm from `path` at runtime
+    /// instead of the platform-default search path. Requires the `dlopen` feature.
+    ///
+    /// # Errors
+    /// Returns `Error::LibraryNotFound` if `path` could not be opened or is
+    /// missing a required symbol.
+    #[cfg(feature = "dlopen")]
+    pub fn load_from(path: &str, priority_boost: bool) -> Result<Self> {
+        Self::new_dynamic(Some(path), priority_boost)
+    }
+
+    #[cfg(feature = "dlopen")]
+    fn new_dynamic(path: Option<&str>, priority_boost: bool) -> Result<Self> {
+        use crate::error::Error;
+
+        let lib = norm_sys::dlopen::library(path).map_err(|e| Error::LibraryNotFound(e.to_string()))?;
+        let handle = unsafe { (lib.norm_create_instance)(priority_boost) };
         unsafe { check_handle(handle, NORM_INSTANCE_INVALID)? };
         Ok(Instance { handle })
     }
@@ -89,6 +117,14 @@ impl Instance {
     /// and `wait` was `false`, or `Err` if an error occurred
     pub fn next_event(&self, wait: bool) -> Result<Option<Event>> {
         let mut raw_event = unsafe { mem::zeroed::<NormEvent>() };
+
+        #[cfg(feature = "dlopen")]
+        let success = {
+            use crate::error::Error;
+            let lib = norm_sys::dlopen::library(None).map_err(|e| Error::LibraryNotFound(e.to_string()))?;
+            unsafe { (lib.norm_get_next_event)(self.handle, &mut raw_event, wait) }
+        };
+        #[cfg(not(feature = "dlopen"))]
         let success = unsafe { NormGetNextEvent(self.handle, &mut raw_event, wait) };
 
         if !success {
@@ -110,6 +146,22 @@ impl Instance {
         EventIterator { instance: self }
     }
 
+    /// Create an async stream of NORM events, backed by the instance's
+    /// readiness descriptor.
+    ///
+    /// Unlike [`Instance::events`], this does not block a dedicated thread:
+    /// the returned stream only calls the non-blocking `NormGetNextEvent`
+    /// when the descriptor signals readiness via tokio's reactor, so it can
+    /// be awaited alongside other async I/O.
+    ///
+    /// # Errors
+    /// Returns an error if the instance's descriptor could not be registered
+    /// with the tokio reactor.
+    #[cfg(all(unix, feature = "tokio"))]
+    pub fn event_stream(&self) -> std::io::Result<crate::reactor::NormEventStream<'_>> {
+        crate::reactor::NormEventStream::new(self)
+    }
+
     /// Create a new NORM session
     ///
     /// # Arguments
@@ -137,7 +189,17 @@ impl Instance {
     /// The file descriptor for the NORM instance
     #[cfg(unix)]
     pub fn descriptor(&self) -> RawFd {
-        unsafe { NormGetDescriptor(self.handle) }
+        #[cfg(feature = "dlopen")]
+        {
+            match norm_sys::dlopen::library(None) {
+                Ok(lib) => unsafe { (lib.norm_get_descriptor)(self.handle) },
+                Err(_) => -1,
+            }
+        }
+        #[cfg(not(feature = "dlopen"))]
+        unsafe {
+            NormGetDescriptor(self.handle)
+        }
     }
 
     /// Open a debug log file
@@ -186,7 +248,16 @@ impl Instance {
 
 impl Drop for Instance {
     fn drop(&mut self) {
-        unsafe { NormDestroyInstance(self.handle) };
+        #[cfg(feature = "dlopen")]
+        {
+            if let Ok(lib) = norm_sys::dlopen::library(None) {
+                unsafe { (lib.norm_destroy_instance)(self.handle) };
+            }
+        }
+        #[cfg(not(feature = "dlopen"))]
+        unsafe {
+            NormDestroyInstance(self.handle)
+        };
     }
 }
 
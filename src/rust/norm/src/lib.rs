@@ -11,6 +11,13 @@
 //! - Iterator-based event handling
 //! - Ergonomic multicast configuration
 //! - Optional async support with tokio (feature = "tokio")
+//! - Optional fragmented-MP4 live media source (feature = "media")
+//! - Optional serde support for event/enum serialization (feature = "serde")
+//! - Optional structured event telemetry via tracing (feature = "tracing")
+//! - Optional length-delimited typed message framing over streams (feature = "codec")
+//! - Optional request/response RPC layer over session objects (feature = "tokio")
+//! - Optional runtime dynamic loading of libnorm via `dlopen` (feature = "dlopen", see `norm_sys::dlopen`)
+//! - Optional `mio::event::Source` integration for custom reactors (feature = "mio")
 
 // Re-export norm-sys for advanced users
 pub use norm_sys;
@@ -24,21 +31,52 @@ mod object;
 mod node;
 mod event;
 mod multicast;
+mod manager;
+mod supervisor;
+mod shared;
+mod progress;
 
 // Optional modules
-// Note: Async support with tokio is planned for future implementation
-// #[cfg(feature = "tokio")]
-// pub mod tokio;
+#[cfg(feature = "media")]
+pub mod media;
+
+#[cfg(all(unix, feature = "tokio"))]
+pub mod reactor;
+
+#[cfg(feature = "serde")]
+pub mod event_record;
+
+#[cfg(feature = "tracing")]
+pub mod logging;
+
+#[cfg(feature = "codec")]
+pub mod codec;
+
+#[cfg(feature = "tokio")]
+pub mod rpc;
+
+#[cfg(all(unix, feature = "mio"))]
+pub mod mio;
 
 // Public re-exports
 pub use error::{Error, Result};
 pub use types::*;
 pub use instance::Instance;
-pub use session::Session;
-pub use object::Object;
+pub use session::{Session, SenderConfig, CongestionControl, RateControlMode, SessionReport};
+pub use object::{Object, StreamReader, NormStreamReader, NormStreamWriter, MessageStream};
 pub use node::Node;
 pub use event::Event;
-pub use multicast::{MulticastConfig, MulticastExt, is_multicast_address};
+pub use multicast::{MulticastConfig, MulticastExt, MulticastScope, is_multicast_address, is_multicast_ip};
+pub use manager::{SessionManager, TaggedEvent};
+pub use supervisor::{ReconnectStrategy, Supervisor, SupervisorEvent};
+pub use shared::{SharedInstance, SharedSession};
+pub use progress::ProgressTracker;
+
+#[cfg(feature = "serde")]
+pub use event_record::EventRecord;
+
+#[cfg(feature = "tracing")]
+pub use logging::{log_event, log_event_with_session};
 
 // Version information
 pub const VERSION_MAJOR: u32 = norm_sys::NORM_VERSION_MAJOR as u32;
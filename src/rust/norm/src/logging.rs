@@ -0,0 +1,66 @@
+//! Structured `tracing` telemetry for NORM events, behind the `tracing` feature.
+//!
+//! Wires every decoded [`EventType`] into a `tracing` call with fields for
+//! the node, session, and object involved, and a severity chosen per event
+//! kind, so operators can attach a subscriber to a running session and get
+//! per-event telemetry without hand-matching over the 29 event variants.
+
+use crate::event::Event;
+use crate::node::Node;
+use crate::object::Object;
+use crate::session::Session;
+use crate::types::EventType;
+use norm_sys::{NORM_NODE_INVALID, NORM_OBJECT_INVALID};
+
+/// Emit a `tracing` event for `event`.
+///
+/// Severity follows the event's operational weight: `SendError`,
+/// `RxObjectAborted` and `UserTimeout` are logged at `error`; remote-sender
+/// liveness churn (`RemoteSenderReset`/`Inactive`/`Purged`) at `warn`;
+/// congestion-control chatter (`GrttUpdated`/`CcActive`/`CcInactive`/
+/// `TxRateChanged`) at `debug`; everything else at `info`. Fields carry the
+/// resolved node id and object transport id when the event has them.
+pub fn log_event(event: &Event) {
+    let name = event.event_type.to_string();
+    let node_id = if event.node == NORM_NODE_INVALID {
+        None
+    } else {
+        Some(Node::from_handle_unowned(event.node).id())
+    };
+    let object_id = if event.object == NORM_OBJECT_INVALID {
+        None
+    } else {
+        Some(Object::from_handle_unowned(event.object).transport_id())
+    };
+
+    match event.event_type {
+        EventType::SendError | EventType::RxObjectAborted | EventType::UserTimeout => {
+            tracing::error!(event = %name, node = ?node_id, object = ?object_id, "NORM event");
+        }
+        EventType::RemoteSenderReset | EventType::RemoteSenderInactive | EventType::RemoteSenderPurged => {
+            tracing::warn!(event = %name, node = ?node_id, object = ?object_id, "NORM event");
+        }
+        EventType::GrttUpdated | EventType::CcActive | EventType::CcInactive | EventType::TxRateChanged => {
+            tracing::debug!(event = %name, node = ?node_id, object = ?object_id, "NORM event");
+        }
+        _ => {
+            tracing::info!(event = %name, node = ?node_id, object = ?object_id, "NORM event");
+        }
+    }
+}
+
+/// Like [`log_event`], but also records the live GRTT estimate and transmit
+/// rate on `session` as numeric fields for `GrttUpdated`/`TxRateChanged`
+/// events, since those values live on the session rather than the event
+/// itself.
+pub fn log_event_with_session(event: &Event, session: &Session) {
+    match event.event_type {
+        EventType::GrttUpdated => {
+            tracing::debug!(event = %event.event_type, grtt_seconds = session.grtt_estimate(), "NORM event");
+        }
+        EventType::TxRateChanged => {
+            tracing::debug!(event = %event.event_type, tx_rate_bps = session.tx_rate(), "NORM event");
+        }
+        _ => log_event(event),
+    }
+}
@@ -0,0 +1,169 @@
+//! Multi-session management with unified event demultiplexing.
+//!
+//! A single [`Instance`] can own many concurrently-running [`Session`]s (e.g.
+//! one per multicast group), but `Instance::events()`/`next_event` hands back
+//! events for whichever session produced them without attribution. Wrap that
+//! up into a [`SessionManager`] so one thread can drive dozens of
+//! senders/receivers from a single merged event loop, the way the examples'
+//! one-instance-one-session pattern could not.
+
+use crate::error::{Error, Result};
+use crate::event::Event;
+use crate::instance::Instance;
+use crate::object::Object;
+use crate::session::Session;
+use crate::types::{EventType, NodeId, SessionId};
+use norm_sys::NormSessionHandle;
+use std::collections::HashMap;
+
+/// A NORM event tagged with the [`SessionId`] of the session that produced it.
+#[derive(Debug, Clone, Copy)]
+pub struct TaggedEvent {
+    /// The id the event's originating session was registered under, or
+    /// `None` if the event came from a session handle this manager never
+    /// registered (e.g. one created directly via `SessionManager::instance`
+    /// rather than through `SessionManager::register`).
+    pub session_id: Option<SessionId>,
+    /// The underlying NORM event
+    pub event: Event,
+}
+
+/// Owns many [`Session`]s on a shared [`Instance`] and demultiplexes their events.
+///
+/// Sessions are registered under a caller-chosen [`SessionId`], which is then
+/// used to address `file_enqueue`/`stream_open` calls and to tag events
+/// pulled from the merged event loop.
+pub struct SessionManager {
+    instance: Instance,
+    sessions: HashMap<SessionId, Session>,
+    handles: HashMap<NormSessionHandle, SessionId>,
+}
+
+impl SessionManager {
+    /// Create a manager that will register sessions on the given instance
+    pub fn new(instance: Instance) -> Self {
+        SessionManager {
+            instance,
+            sessions: HashMap::new(),
+            handles: HashMap::new(),
+        }
+    }
+
+    /// The underlying NORM instance
+    pub fn instance(&self) -> &Instance {
+        &self.instance
+    }
+
+    /// Create and register a new session under `session_id`
+    ///
+    /// # Errors
+    /// Returns an error if the session could not be created, or if
+    /// `session_id` is already registered
+    pub fn register<A: AsRef<str>>(
+        &mut self,
+        session_id: SessionId,
+        address: A,
+        port: u16,
+        local_node_id: NodeId,
+    ) -> Result<&Session> {
+        if self.sessions.contains_key(&session_id) {
+            return Err(Error::InvalidParameter);
+        }
+
+        let session = self.instance.create_session(address, port, local_node_id)?;
+        self.handles.insert(session.handle(), session_id);
+        self.sessions.insert(session_id, session);
+
+        Ok(self.sessions.get(&session_id).expect("just inserted"))
+    }
+
+    /// Look up a registered session by id
+    pub fn session(&self, session_id: SessionId) -> Option<&Session> {
+        self.sessions.get(&session_id)
+    }
+
+    /// Enqueue a file for transmission on a registered session
+    pub fn file_enqueue<P: AsRef<str>>(
+        &self,
+        session_id: SessionId,
+        file_path: P,
+        info: Option<&[u8]>,
+    ) -> Result<Object> {
+        self.session(session_id).ok_or(Error::InvalidParameter)?.file_enqueue(file_path, info)
+    }
+
+    /// Open a stream for transmission on a registered session
+    pub fn stream_open(&self, session_id: SessionId, buffer_size: u32, info: Option<&[u8]>) -> Result<Object> {
+        self.session(session_id).ok_or(Error::InvalidParameter)?.stream_open(buffer_size, info)
+    }
+
+    /// Block for the next event across every registered session
+    ///
+    /// # Returns
+    /// `Ok(Some(event))` tagged with its originating session id -- `None` if
+    /// the event's session handle isn't one this manager registered -- or
+    /// `Ok(None)` if no event was available, or `Err` if an error occurred
+    pub fn next_event(&self, wait: bool) -> Result<Option<TaggedEvent>> {
+        let event = match self.instance.next_event(wait)? {
+            Some(event) => event,
+            None => return Ok(None),
+        };
+
+        let session_id = self.handles.get(&event.session).copied();
+        Ok(Some(TaggedEvent { session_id, event }))
+    }
+
+    /// Iterate over events across every registered session, blocking until each is available
+    pub fn events(&self) -> impl Iterator<Item = TaggedEvent> + '_ {
+        std::iter::from_fn(move || match self.next_event(true) {
+            Ok(Some(tagged)) => Some(tagged),
+            _ => None,
+        })
+    }
+
+    /// Gracefully stop and unregister a single session
+    ///
+    /// Stops the session as both a sender and receiver before dropping it;
+    /// any objects it owns are released along with it.
+    pub fn shutdown(&mut self, session_id: SessionId) {
+        if let Some(session) = self.sessions.remove(&session_id) {
+            session.stop_sender();
+            session.stop_receiver();
+            self.handles.remove(&session.handle());
+        }
+    }
+
+    /// Stop every registered sender, wait for each one's `TxFlushCompleted`
+    /// so in-flight data is accounted for, then unregister all sessions
+    ///
+    /// Blocks until every registered session has reported its flush (or its
+    /// event stream ends), so in-flight data is never silently dropped on
+    /// shutdown.
+    pub fn shutdown_all(&mut self) {
+        for session in self.sessions.values() {
+            session.stop_sender();
+        }
+
+        let mut pending: std::collections::HashSet<SessionId> = self.sessions.keys().copied().collect();
+        while !pending.is_empty() {
+            let tagged = match self.next_event(true) {
+                Ok(Some(tagged)) => tagged,
+                // An error or a closed event stream -- nothing left to wait on.
+                _ => break,
+            };
+
+            if tagged.event.event_type == EventType::TxFlushCompleted {
+                if let Some(session_id) = tagged.session_id {
+                    pending.remove(&session_id);
+                }
+            }
+        }
+
+        for session in self.sessions.values() {
+            session.stop_receiver();
+        }
+
+        self.sessions.clear();
+        self.handles.clear();
+    }
+}
@@ -0,0 +1,461 @@
+//! Fragmented MP4 live media source for NORM streams.
+//!
+//! This module turns a fragmented MP4 (`fMP4`) file -- an `ftyp`+`moov`
+//! initialization segment (with `empty_moov`, i.e. no sample data in `moov`)
+//! followed by a sequence of `moof`+`mdat` media fragments -- into a paced
+//! live stream over a NORM stream [`Object`], in the spirit of moq-style
+//! fragment-per-chunk pipelines.
+
+use crate::error::{Error, Result};
+use crate::object::{Object, STREAM_VACANCY_POLL_INTERVAL};
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// One decodable unit extracted from a fragmented MP4 file.
+#[derive(Debug, Clone)]
+pub struct Fragment {
+    /// The raw `moof`+`mdat` box pair, ready to hand to `Object::stream_write`.
+    pub data: Vec<u8>,
+    /// Presentation timestamp, in milliseconds, derived from the fragment's `tfdt` box.
+    pub timestamp_ms: u64,
+    /// Whether the fragment's first sample is a sync (key) frame.
+    pub keyframe: bool,
+}
+
+/// A fragmented MP4 file, parsed into an initialization segment and a
+/// sequence of media fragments.
+///
+/// The initialization segment is kept separate from the fragments so callers
+/// can pass it as a stream's `NORM_INFO` payload (see
+/// `Session::stream_open`) while fragments are read one at a time with
+/// [`FragmentedSource::next_fragment`].
+#[derive(Debug)]
+pub struct FragmentedSource {
+    file: File,
+    init_segment: Vec<u8>,
+    timescale: u32,
+}
+
+impl FragmentedSource {
+    /// Open a fragmented MP4 file and parse its initialization segment.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be read, or does not begin with a
+    /// valid `ftyp`+`moov` initialization segment containing an `mvhd` box.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self> {
+        let mut file = File::open(path.as_ref())
+            .map_err(|e| Error::FileError(format!("failed to open '{}': {}", path.as_ref().display(), e)))?;
+        let init_segment = read_init_segment(&mut file)?;
+        let timescale = parse_init_timescale(&init_segment)?;
+        Ok(FragmentedSource { file, init_segment, timescale })
+    }
+
+    /// The file's initialization segment (`ftyp`+`moov`), suitable for use as
+    /// a stream's `NORM_INFO` payload.
+    pub fn init_segment(&self) -> &[u8] {
+        &self.init_segment
+    }
+
+    /// The media timescale (units per second) from the initialization segment's `mvhd` box.
+    pub fn timescale(&self) -> u32 {
+        self.timescale
+    }
+
+    /// Parse and return the next `moof`+`mdat` fragment, or `None` at end of file.
+    ///
+    /// # Errors
+    /// Returns an error if the next top-level boxes are not a well-formed
+    /// `moof`+`mdat` pair, or if the `moof` box is missing its `tfdt` box.
+    pub fn next_fragment(&mut self) -> Result<Option<Fragment>> {
+        let (moof_name, mut moof_raw) = match read_top_level_box(&mut self.file)? {
+            Some(b) => b,
+            None => return Ok(None),
+        };
+        if moof_name != *b"moof" {
+            return Err(Error::OperationFailed(format!(
+                "expected 'moof' box, found '{}'",
+                String::from_utf8_lossy(&moof_name)
+            )));
+        }
+
+        let (mdat_name, mdat_raw) = read_top_level_box(&mut self.file)?.ok_or_else(|| {
+            Error::OperationFailed("truncated fragment: missing 'mdat' box".to_string())
+        })?;
+        if mdat_name != *b"mdat" {
+            return Err(Error::OperationFailed(format!(
+                "expected 'mdat' box, found '{}'",
+                String::from_utf8_lossy(&mdat_name)
+            )));
+        }
+
+        let traf_payload = child_boxes(&moof_raw[8..])
+            .into_iter()
+            .find(|(name, _)| name == b"traf")
+            .map(|(_, payload)| payload)
+            .ok_or_else(|| Error::OperationFailed("missing 'traf' box in 'moof'".to_string()))?;
+
+        let tfdt_payload = child_boxes(traf_payload)
+            .into_iter()
+            .find(|(name, _)| name == b"tfdt")
+            .map(|(_, payload)| payload)
+            .ok_or_else(|| Error::OperationFailed("missing 'tfdt' box in 'traf'".to_string()))?;
+        let base_decode_time = parse_tfdt_time(tfdt_payload)?;
+
+        let keyframe = child_boxes(traf_payload)
+            .into_iter()
+            .find(|(name, _)| name == b"trun")
+            .map(|(_, payload)| parse_trun_keyframe(payload))
+            .unwrap_or(true);
+
+        let timestamp_ms = base_decode_time.saturating_mul(1000) / self.timescale.max(1) as u64;
+
+        moof_raw.extend_from_slice(&mdat_raw);
+
+        Ok(Some(Fragment { data: moof_raw, timestamp_ms, keyframe }))
+    }
+}
+
+/// Pace a fragmented source out over a NORM stream in real time.
+///
+/// Sleeps until each fragment's `timestamp_ms` relative to the wall-clock
+/// time `pump` was called, writes the fragment's `moof`+`mdat` blob with
+/// `stream_write`, and marks an end-of-message boundary with
+/// `stream_mark_eom` so receivers get clean decode units.
+///
+/// # Errors
+/// Returns an error if a fragment cannot be parsed, or if writing to the
+/// stream fails.
+pub fn pump(source: &mut FragmentedSource, stream: &Object) -> Result<()> {
+    let start = Instant::now();
+
+    while let Some(fragment) = source.next_fragment()? {
+        let target = Duration::from_millis(fragment.timestamp_ms);
+        let elapsed = start.elapsed();
+        if target > elapsed {
+            thread::sleep(target - elapsed);
+        }
+
+        let mut sent = 0;
+        while sent < fragment.data.len() {
+            let n = stream.stream_write(&fragment.data[sent..])?;
+            if n == 0 {
+                thread::sleep(STREAM_VACANCY_POLL_INTERVAL);
+                continue;
+            }
+            sent += n;
+        }
+        stream.stream_mark_eom()?;
+    }
+
+    Ok(())
+}
+
+/// Read top-level boxes from the start of the file up to and including `moov`.
+fn read_init_segment(file: &mut File) -> Result<Vec<u8>> {
+    let mut init = Vec::new();
+
+    loop {
+        let (name, raw) = read_top_level_box(file)?.ok_or_else(|| {
+            Error::OperationFailed("unexpected end of file while reading initialization segment".to_string())
+        })?;
+        let is_moov = name == *b"moov";
+        init.extend_from_slice(&raw);
+        if is_moov {
+            return Ok(init);
+        }
+    }
+}
+
+/// Find the `mvhd` timescale nested inside the initialization segment's `moov` box.
+fn parse_init_timescale(init_segment: &[u8]) -> Result<u32> {
+    let moov_payload = child_boxes(init_segment)
+        .into_iter()
+        .find(|(name, _)| name == b"moov")
+        .map(|(_, payload)| payload)
+        .ok_or_else(|| Error::OperationFailed("missing 'moov' box in initialization segment".to_string()))?;
+
+    let mvhd_payload = child_boxes(moov_payload)
+        .into_iter()
+        .find(|(name, _)| name == b"mvhd")
+        .map(|(_, payload)| payload)
+        .ok_or_else(|| Error::OperationFailed("missing 'mvhd' box in 'moov'".to_string()))?;
+
+    parse_mvhd_timescale(mvhd_payload)
+}
+
+/// Parse the timescale field out of an `mvhd` box body (after the 4-byte version/flags header).
+fn parse_mvhd_timescale(mvhd: &[u8]) -> Result<u32> {
+    let version = *mvhd.first().ok_or_else(|| Error::OperationFailed("empty 'mvhd' box".to_string()))?;
+    let timescale_offset = if version == 1 { 4 + 8 + 8 } else { 4 + 4 + 4 };
+    let end = timescale_offset + 4;
+
+    if mvhd.len() < end {
+        return Err(Error::OperationFailed("truncated 'mvhd' box".to_string()));
+    }
+
+    Ok(u32::from_be_bytes(mvhd[timescale_offset..end].try_into().unwrap()))
+}
+
+/// Parse the base media decode time out of a `tfdt` box body.
+fn parse_tfdt_time(tfdt: &[u8]) -> Result<u64> {
+    let version = *tfdt.first().ok_or_else(|| Error::OperationFailed("empty 'tfdt' box".to_string()))?;
+
+    if version == 1 {
+        if tfdt.len() < 12 {
+            return Err(Error::OperationFailed("truncated 'tfdt' box".to_string()));
+        }
+        Ok(u64::from_be_bytes(tfdt[4..12].try_into().unwrap()))
+    } else {
+        if tfdt.len() < 8 {
+            return Err(Error::OperationFailed("truncated 'tfdt' box".to_string()));
+        }
+        Ok(u32::from_be_bytes(tfdt[4..8].try_into().unwrap()) as u64)
+    }
+}
+
+/// Determine whether a `trun` box's first sample is a sync sample.
+///
+/// Defaults to `true` (keyframe) when the box carries no per-sample or
+/// first-sample flags, matching the common case of a fragment with exactly
+/// one sample.
+fn parse_trun_keyframe(trun: &[u8]) -> bool {
+    if trun.len() < 8 {
+        return true;
+    }
+
+    let flags = u32::from_be_bytes([0, trun[1], trun[2], trun[3]]);
+    let mut offset = 8; // version/flags(4) + sample_count(4)
+
+    if flags & 0x0000_0001 != 0 {
+        offset += 4; // data_offset_present
+    }
+
+    let sample_flags = if flags & 0x0000_0004 != 0 {
+        // first_sample_flags_present
+        trun.get(offset..offset + 4).map(|b| u32::from_be_bytes(b.try_into().unwrap()))
+    } else if flags & 0x0000_0400 != 0 {
+        // sample_flags_present, for the first entry in the per-sample table
+        let mut pos = offset;
+        if flags & 0x0000_0100 != 0 {
+            pos += 4; // sample_duration_present
+        }
+        if flags & 0x0000_0200 != 0 {
+            pos += 4; // sample_size_present
+        }
+        trun.get(pos..pos + 4).map(|b| u32::from_be_bytes(b.try_into().unwrap()))
+    } else {
+        None
+    };
+
+    match sample_flags {
+        // The "sample is difference sample" bit means this is NOT a sync sample.
+        Some(f) => f & 0x0001_0000 == 0,
+        None => true,
+    }
+}
+
+/// Read one top-level ISO-BMFF box (32- or 64-bit size) from the current file position.
+///
+/// Returns `Ok(None)` at a clean end-of-file (no bytes read for the header).
+fn read_top_level_box(file: &mut File) -> Result<Option<([u8; 4], Vec<u8>)>> {
+    let mut header = [0u8; 8];
+    match file.read(&mut header[..1]) {
+        Ok(0) => return Ok(None),
+        Ok(_) => {}
+        Err(e) => return Err(Error::OperationFailed(format!("failed to read box header: {}", e))),
+    }
+    if let Err(e) = file.read_exact(&mut header[1..]) {
+        return Err(Error::OperationFailed(format!("failed to read box header: {}", e)));
+    }
+
+    let mut raw = header.to_vec();
+    let size32 = u32::from_be_bytes(header[0..4].try_into().unwrap());
+    let name: [u8; 4] = header[4..8].try_into().unwrap();
+
+    // size == 0 is the ISO-BMFF convention for "box extends to EOF" --
+    // commonly used by a stream's final `mdat`, which has no way to know its
+    // total size up front. Read whatever remains of the file as the body.
+    if size32 == 0 {
+        let mut body = Vec::new();
+        file.read_to_end(&mut body)
+            .map_err(|e| Error::OperationFailed(format!("failed to read '{}' box body: {}", String::from_utf8_lossy(&name), e)))?;
+        raw.extend_from_slice(&body);
+        return Ok(Some((name, raw)));
+    }
+
+    let box_size = if size32 == 1 {
+        let mut ext = [0u8; 8];
+        file.read_exact(&mut ext)
+            .map_err(|e| Error::OperationFailed(format!("failed to read extended box size: {}", e)))?;
+        raw.extend_from_slice(&ext);
+        u64::from_be_bytes(ext)
+    } else {
+        size32 as u64
+    };
+
+    if (box_size as usize) < raw.len() {
+        return Err(Error::OperationFailed(format!(
+            "invalid box size for '{}'",
+            String::from_utf8_lossy(&name)
+        )));
+    }
+
+    let mut body = vec![0u8; box_size as usize - raw.len()];
+    file.read_exact(&mut body)
+        .map_err(|e| Error::OperationFailed(format!("failed to read '{}' box body: {}", String::from_utf8_lossy(&name), e)))?;
+    raw.extend_from_slice(&body);
+
+    Ok(Some((name, raw)))
+}
+
+/// Split a box's payload into its direct child boxes (32- or 64-bit size).
+fn child_boxes(data: &[u8]) -> Vec<([u8; 4], &[u8])> {
+    let mut boxes = Vec::new();
+    let mut offset = 0;
+
+    while offset + 8 <= data.len() {
+        let size32 = u32::from_be_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        let name: [u8; 4] = data[offset + 4..offset + 8].try_into().unwrap();
+
+        let (header_len, box_size) = if size32 == 1 {
+            if offset + 16 > data.len() {
+                break;
+            }
+            let size64 = u64::from_be_bytes(data[offset + 8..offset + 16].try_into().unwrap()) as usize;
+            (16, size64)
+        } else if size32 == 0 {
+            (8, data.len() - offset)
+        } else {
+            (8, size32)
+        };
+
+        if box_size < header_len || offset + box_size > data.len() {
+            break;
+        }
+
+        boxes.push((name, &data[offset + header_len..offset + box_size]));
+        offset += box_size;
+    }
+
+    boxes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn make_box(name: &[u8; 4], payload: &[u8]) -> Vec<u8> {
+        let mut b = ((payload.len() + 8) as u32).to_be_bytes().to_vec();
+        b.extend_from_slice(name);
+        b.extend_from_slice(payload);
+        b
+    }
+
+    #[test]
+    fn test_parse_mvhd_timescale_version_0() {
+        let mut mvhd = vec![0u8]; // version
+        mvhd.extend_from_slice(&[0, 0, 0]); // flags
+        mvhd.extend_from_slice(&[0u8; 4]); // creation_time
+        mvhd.extend_from_slice(&[0u8; 4]); // modification_time
+        mvhd.extend_from_slice(&90000u32.to_be_bytes()); // timescale
+        assert_eq!(parse_mvhd_timescale(&mvhd).unwrap(), 90000);
+    }
+
+    #[test]
+    fn test_parse_mvhd_timescale_version_1() {
+        let mut mvhd = vec![1u8]; // version
+        mvhd.extend_from_slice(&[0, 0, 0]); // flags
+        mvhd.extend_from_slice(&[0u8; 8]); // creation_time
+        mvhd.extend_from_slice(&[0u8; 8]); // modification_time
+        mvhd.extend_from_slice(&48000u32.to_be_bytes()); // timescale
+        assert_eq!(parse_mvhd_timescale(&mvhd).unwrap(), 48000);
+    }
+
+    #[test]
+    fn test_parse_mvhd_timescale_truncated() {
+        let mvhd = vec![0u8, 0, 0, 0];
+        assert!(parse_mvhd_timescale(&mvhd).is_err());
+    }
+
+    #[test]
+    fn test_parse_tfdt_time_version_0() {
+        let mut tfdt = vec![0u8, 0, 0, 0]; // version/flags
+        tfdt.extend_from_slice(&12345u32.to_be_bytes());
+        assert_eq!(parse_tfdt_time(&tfdt).unwrap(), 12345);
+    }
+
+    #[test]
+    fn test_parse_tfdt_time_version_1() {
+        let mut tfdt = vec![1u8, 0, 0, 0]; // version/flags
+        tfdt.extend_from_slice(&9_999_999_999u64.to_be_bytes());
+        assert_eq!(parse_tfdt_time(&tfdt).unwrap(), 9_999_999_999);
+    }
+
+    #[test]
+    fn test_parse_trun_keyframe_defaults_true_when_no_flags() {
+        let trun = vec![0u8; 8];
+        assert!(parse_trun_keyframe(&trun));
+    }
+
+    #[test]
+    fn test_parse_trun_keyframe_first_sample_flags_non_sync() {
+        let mut trun = vec![0u8, 0, 0x00, 0x04]; // first_sample_flags_present
+        trun.extend_from_slice(&1u32.to_be_bytes()); // sample_count
+        trun.extend_from_slice(&0x0001_0000u32.to_be_bytes()); // difference sample
+        assert!(!parse_trun_keyframe(&trun));
+    }
+
+    #[test]
+    fn test_parse_trun_keyframe_first_sample_flags_sync() {
+        let mut trun = vec![0u8, 0, 0x00, 0x04]; // first_sample_flags_present
+        trun.extend_from_slice(&1u32.to_be_bytes()); // sample_count
+        trun.extend_from_slice(&0u32.to_be_bytes()); // not a difference sample
+        assert!(parse_trun_keyframe(&trun));
+    }
+
+    #[test]
+    fn test_child_boxes_splits_siblings() {
+        let moov = [make_box(b"mvhd", &[1, 2, 3]), make_box(b"trak", &[4, 5])].concat();
+        let boxes = child_boxes(&moov);
+        assert_eq!(boxes.len(), 2);
+        assert_eq!(&boxes[0].0, b"mvhd");
+        assert_eq!(boxes[0].1, &[1, 2, 3]);
+        assert_eq!(&boxes[1].0, b"trak");
+        assert_eq!(boxes[1].1, &[4, 5]);
+    }
+
+    #[test]
+    fn test_child_boxes_stops_at_truncated_trailer() {
+        let mut data = make_box(b"mvhd", &[1, 2, 3]);
+        data.extend_from_slice(&[0, 0, 0]); // fewer than 8 trailing bytes
+        let boxes = child_boxes(&data);
+        assert_eq!(boxes.len(), 1);
+    }
+
+    #[test]
+    fn test_read_top_level_box_size_zero_extends_to_eof() {
+        use std::io::Write;
+
+        let mut path = std::env::temp_dir();
+        path.push(format!("norm-media-test-{}-{}.mp4", std::process::id(), line!()));
+
+        let mut size_zero_box = 0u32.to_be_bytes().to_vec();
+        size_zero_box.extend_from_slice(b"mdat");
+        size_zero_box.extend_from_slice(&[9, 8, 7, 6, 5]);
+
+        {
+            let mut f = File::create(&path).unwrap();
+            f.write_all(&size_zero_box).unwrap();
+        }
+
+        let mut file = File::open(&path).unwrap();
+        let (name, raw) = read_top_level_box(&mut file).unwrap().unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(&name, b"mdat");
+        assert_eq!(raw, size_zero_box);
+    }
+}
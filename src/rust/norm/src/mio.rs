@@ -0,0 +1,58 @@
+//! Generic `mio` event-source integration, behind the `mio` feature.
+//!
+//! Embedders running their own `mio`-based event loop can register an
+//! [`InstancePoll`] alongside their other sockets instead of spawning a
+//! dedicated thread for [`Instance::events`]. NORM's descriptor (from
+//! `NormGetDescriptor`) becomes readable whenever events are queued, so
+//! [`InstancePoll`] implements [`mio::event::Source`] by delegating to
+//! `mio::unix::SourceFd` and registering interest in readability only.
+//! After a readiness notification, call [`InstancePoll::drain_events`] to
+//! pull every queued event with non-blocking `NormGetNextEvent` calls --
+//! like [`crate::reactor::NormEventStream`], the descriptor only signals
+//! once per burst, so all of them must be drained before the next `poll`.
+
+use crate::event::Event;
+use crate::instance::Instance;
+use mio::event::Source;
+use mio::unix::SourceFd;
+use mio::{Interest, Registry, Token};
+
+/// Wraps an [`Instance`] so it can be registered with a `mio::Poll`.
+///
+/// Borrows the instance for its lifetime; the wrapper itself holds no
+/// state beyond that borrow, since readiness tracking lives in the
+/// `mio::Poll` the caller registers it with.
+pub struct InstancePoll<'a> {
+    instance: &'a Instance,
+}
+
+impl<'a> InstancePoll<'a> {
+    /// Wrap `instance` for registration with a `mio::Poll`.
+    pub fn new(instance: &'a Instance) -> Self {
+        InstancePoll { instance }
+    }
+
+    /// Pull every event currently queued on the instance.
+    ///
+    /// Call this after a readiness notification for this source's token.
+    /// Each call to `NormGetNextEvent` is non-blocking, so the returned
+    /// iterator is exhausted as soon as no event is available rather than
+    /// blocking for one.
+    pub fn drain_events(&self) -> impl Iterator<Item = Event> + '_ {
+        std::iter::from_fn(move || self.instance.next_event(false).ok().flatten())
+    }
+}
+
+impl<'a> Source for InstancePoll<'a> {
+    fn register(&mut self, registry: &Registry, token: Token, interests: Interest) -> std::io::Result<()> {
+        SourceFd(&self.instance.descriptor()).register(registry, token, interests)
+    }
+
+    fn reregister(&mut self, registry: &Registry, token: Token, interests: Interest) -> std::io::Result<()> {
+        SourceFd(&self.instance.descriptor()).reregister(registry, token, interests)
+    }
+
+    fn deregister(&mut self, registry: &Registry) -> std::io::Result<()> {
+        SourceFd(&self.instance.descriptor()).deregister(registry)
+    }
+}
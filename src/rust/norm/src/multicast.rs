@@ -1,9 +1,57 @@
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::session::Session;
-use std::net::IpAddr;
+use if_addrs::IfAddr;
+use std::net::{IpAddr, Ipv6Addr, SocketAddr};
 use std::fmt;
 use std::str::FromStr;
 
+/// The scope of an IPv6 multicast group, extracted from the scope nibble `X`
+/// of its `ff0X::` prefix (RFC 4291 section 2.7).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MulticastScope {
+    /// Scope 0x1: does not leave the originating interface
+    InterfaceLocal,
+    /// Scope 0x2: does not leave the local link
+    LinkLocal,
+    /// Scope 0x5: does not leave the local site
+    SiteLocal,
+    /// Scope 0xe: unrestricted, global scope
+    Global,
+    /// Any other scope value, carried as-is
+    Other(u8),
+}
+
+impl MulticastScope {
+    fn from_nibble(nibble: u8) -> Self {
+        match nibble {
+            0x1 => MulticastScope::InterfaceLocal,
+            0x2 => MulticastScope::LinkLocal,
+            0x5 => MulticastScope::SiteLocal,
+            0xe => MulticastScope::Global,
+            other => MulticastScope::Other(other),
+        }
+    }
+
+    /// The largest TTL/hop-limit that stays consistent with this scope, or
+    /// `None` for every scope other than interface-local and link-local.
+    fn max_sensible_ttl(self) -> Option<u8> {
+        match self {
+            MulticastScope::InterfaceLocal => Some(0),
+            MulticastScope::LinkLocal => Some(1),
+            _ => None,
+        }
+    }
+}
+
+fn ipv6_scope(addr: Ipv6Addr) -> Option<MulticastScope> {
+    if !addr.is_multicast() {
+        return None;
+    }
+    let scope_nibble = (addr.segments()[0] & 0x000f) as u8;
+    Some(MulticastScope::from_nibble(scope_nibble))
+}
+
 /// Ergonomic multicast configuration for NORM sessions.
 ///
 /// This struct provides a builder-style API for configuring multicast options
@@ -25,6 +73,8 @@ pub struct MulticastConfig {
     ssm_source: Option<String>,
     /// The type of service (TOS) value for IP packets
     tos: Option<u8>,
+    /// The IPv6 multicast scope of `address`, if it is an IPv6 group
+    scope: Option<MulticastScope>,
 }
 
 impl MulticastConfig {
@@ -37,17 +87,57 @@ impl MulticastConfig {
     /// # Returns
     /// A new multicast configuration
     pub fn new(address: impl Into<String>, port: u16) -> Self {
+        let address = address.into();
+        let scope = IpAddr::from_str(&address).ok().and_then(|ip| match ip {
+            IpAddr::V6(v6) => ipv6_scope(v6),
+            IpAddr::V4(_) => None,
+        });
+
         Self {
-            address: address.into(),
+            address,
             port,
             interface: None,
             ttl: None,
             loopback: None,
             ssm_source: None,
             tos: None,
+            scope,
         }
     }
 
+    /// Construct from a typed IP address, validating up front that it is
+    /// actually a multicast group instead of deferring the failure to `apply()`.
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidParameter` if `addr` is not a multicast address.
+    pub fn from_addr(addr: IpAddr, port: u16) -> Result<Self> {
+        if !is_multicast_ip(addr) {
+            return Err(Error::InvalidParameter);
+        }
+
+        let scope = match addr {
+            IpAddr::V6(v6) => ipv6_scope(v6),
+            IpAddr::V4(_) => None,
+        };
+
+        Ok(Self {
+            address: addr.to_string(),
+            port,
+            interface: None,
+            ttl: None,
+            loopback: None,
+            ssm_source: None,
+            tos: None,
+            scope,
+        })
+    }
+
+    /// The IPv6 multicast scope of the configured group, or `None` for an
+    /// IPv4 group (IPv4 multicast has no analogous scope field).
+    pub fn scope(&self) -> Option<MulticastScope> {
+        self.scope
+    }
+
     /// Set the network interface for multicast
     ///
     /// # Arguments
@@ -60,6 +150,55 @@ impl MulticastConfig {
         self
     }
 
+    /// Auto-select a host network interface for this multicast group.
+    ///
+    /// Enumerates the host's interfaces, filters out loopback, and prefers
+    /// one whose address family matches the configured multicast group (an
+    /// IPv6 group wants a v6-capable interface, and likewise for IPv4),
+    /// falling back to any remaining non-loopback interface. This replaces a
+    /// hardcoded name like `"eth0"` with a choice that survives moving the
+    /// code to a different host.
+    ///
+    /// # Errors
+    /// Returns an error if no suitable interface was found.
+    pub fn auto_interface(mut self) -> Result<Self> {
+        let group_is_v6 = IpAddr::from_str(&self.address).map(|ip| ip.is_ipv6()).unwrap_or(false);
+
+        let interfaces = if_addrs::get_if_addrs()
+            .map_err(|e| Error::OperationFailed(format!("failed to enumerate network interfaces: {e}")))?;
+
+        let chosen = interfaces
+            .iter()
+            .find(|iface| !iface.is_loopback() && matches!(iface.addr, IfAddr::V6(_)) == group_is_v6)
+            .or_else(|| interfaces.iter().find(|iface| !iface.is_loopback()))
+            .ok_or_else(|| Error::OperationFailed("no suitable non-loopback network interface found".to_string()))?;
+
+        self.interface = Some(chosen.name.clone());
+        Ok(self)
+    }
+
+    /// Use the interface that carries `ip_address`, resolving it to its
+    /// kernel interface name.
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidParameter` if `ip_address` does not parse as
+    /// an IP address, or `Error::OperationFailed` if no local interface
+    /// carries it.
+    pub fn interface_by_address(mut self, ip_address: &str) -> Result<Self> {
+        let target: IpAddr = ip_address.parse().map_err(|_| Error::InvalidParameter)?;
+
+        let interfaces = if_addrs::get_if_addrs()
+            .map_err(|e| Error::OperationFailed(format!("failed to enumerate network interfaces: {e}")))?;
+
+        let matched = interfaces
+            .into_iter()
+            .find(|iface| iface.ip() == target)
+            .ok_or_else(|| Error::OperationFailed(format!("no local interface has address {ip_address}")))?;
+
+        self.interface = Some(matched.name);
+        Ok(self)
+    }
+
     /// Set the time-to-live (TTL) for multicast packets
     ///
     /// # Arguments
@@ -120,6 +259,14 @@ impl MulticastConfig {
             session.set_multicast_interface(interface)?;
         }
 
+        if let (Some(scope), Some(ttl)) = (self.scope, self.ttl) {
+            if let Some(max_ttl) = scope.max_sensible_ttl() {
+                if ttl > max_ttl {
+                    return Err(Error::InvalidParameter);
+                }
+            }
+        }
+
         if let Some(ttl) = self.ttl {
             session.set_ttl(ttl)?;
         }
@@ -229,6 +376,18 @@ impl MulticastExt for Session {
     }
 }
 
+impl TryFrom<&str> for MulticastConfig {
+    type Error = Error;
+
+    /// Parse `"address:port"` (IPv6 addresses bracketed, as with
+    /// `std::net::SocketAddr`), validating up front that the address is a
+    /// multicast group.
+    fn try_from(value: &str) -> std::result::Result<Self, Self::Error> {
+        let socket_addr = SocketAddr::from_str(value).map_err(|_| Error::InvalidParameter)?;
+        MulticastConfig::from_addr(socket_addr.ip(), socket_addr.port())
+    }
+}
+
 /// Check if an IP address is a multicast address
 ///
 /// # Arguments
@@ -237,13 +396,14 @@ impl MulticastExt for Session {
 /// # Returns
 /// `true` if the address is a multicast address, `false` otherwise
 pub fn is_multicast_address(addr: &str) -> bool {
-    if let Ok(ip) = IpAddr::from_str(addr) {
-        match ip {
-            IpAddr::V4(ipv4) => ipv4.is_multicast(),
-            IpAddr::V6(ipv6) => ipv6.is_multicast(),
-        }
-    } else {
-        false
+    IpAddr::from_str(addr).map(is_multicast_ip).unwrap_or(false)
+}
+
+/// Check if a typed IP address is a multicast address
+pub fn is_multicast_ip(addr: IpAddr) -> bool {
+    match addr {
+        IpAddr::V4(ipv4) => ipv4.is_multicast(),
+        IpAddr::V6(ipv6) => ipv6.is_multicast(),
     }
 }
 
@@ -336,4 +496,54 @@ mod tests {
         assert_eq!(config.ttl, None);
         assert_eq!(config.interface, None);
     }
+
+    #[test]
+    fn test_from_addr_rejects_non_multicast() {
+        let err = MulticastConfig::from_addr("192.168.1.1".parse().unwrap(), 6003).unwrap_err();
+        assert!(matches!(err, Error::InvalidParameter));
+    }
+
+    #[test]
+    fn test_from_addr_accepts_multicast() {
+        let config = MulticastConfig::from_addr("224.1.2.3".parse().unwrap(), 6003).unwrap();
+        assert_eq!(config.address(), "224.1.2.3");
+        assert_eq!(config.port(), 6003);
+        assert_eq!(config.scope(), None);
+    }
+
+    #[test]
+    fn test_scope_link_local() {
+        let config = MulticastConfig::new("ff02::1", 6003);
+        assert_eq!(config.scope(), Some(MulticastScope::LinkLocal));
+    }
+
+    #[test]
+    fn test_scope_site_local() {
+        let config = MulticastConfig::new("ff05::1:3", 6003);
+        assert_eq!(config.scope(), Some(MulticastScope::SiteLocal));
+    }
+
+    #[test]
+    fn test_try_from_str() {
+        let config = MulticastConfig::try_from("224.1.2.3:6003").unwrap();
+        assert_eq!(config.address(), "224.1.2.3");
+        assert_eq!(config.port(), 6003);
+
+        assert!(MulticastConfig::try_from("192.168.1.1:6003").is_err());
+        assert!(MulticastConfig::try_from("not a socket addr").is_err());
+    }
+
+    #[test]
+    fn test_link_local_scope_bounds_ttl_to_one() {
+        assert_eq!(MulticastScope::LinkLocal.max_sensible_ttl(), Some(1));
+        assert_eq!(MulticastScope::SiteLocal.max_sensible_ttl(), None);
+        assert_eq!(MulticastScope::Global.max_sensible_ttl(), None);
+    }
+
+    #[test]
+    fn test_interface_by_address_rejects_unparseable_address() {
+        let config = MulticastConfig::new("224.1.2.3", 6003);
+        let err = config.interface_by_address("not.an.ip").unwrap_err();
+        assert!(matches!(err, crate::error::Error::InvalidParameter));
+    }
 }
\ No newline at end of file
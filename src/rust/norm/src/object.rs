@@ -1,9 +1,19 @@
 use crate::error::{Error, Result, check_handle};
 use crate::types::*;
 use crate::node::Node;
+use crate::session::Session;
 use norm_sys::*;
+use std::io;
 use std::slice;
 use std::os::raw::c_char;
+use std::time::Duration;
+
+/// How long a blocking, synchronous stream read/write backs off when no
+/// progress can be made right now (no send vacancy, or no data yet), before
+/// re-checking. Used by [`MessageStream::send_message`]/[`StreamReader::read_message`]
+/// and `codec::FramedSender::send`, whose callers have no event loop to
+/// await readiness on instead.
+pub(crate) const STREAM_VACANCY_POLL_INTERVAL: Duration = Duration::from_millis(1);
 
 /// NORM object handle with RAII semantics.
 ///
@@ -50,6 +60,19 @@ impl Object {
         ObjectType::from(obj_type)
     }
 
+    /// Get the transport id NORM assigned this object for wire transmission
+    ///
+    /// This id is only meaningful for the lifetime of the sending session,
+    /// but it is stable and comparable across processes, unlike the raw
+    /// object handle, making it suitable for forwarding object identity over
+    /// e.g. a control-plane IPC channel.
+    ///
+    /// # Returns
+    /// The object's transport id
+    pub fn transport_id(&self) -> ObjectTransportId {
+        unsafe { NormObjectGetTransportId(self.handle) }
+    }
+
     /// Check if the object has info data
     ///
     /// # Returns
@@ -313,4 +336,274 @@ impl Drop for Object {
             unsafe { NormObjectRelease(self.handle) };
         }
     }
+}
+
+/// Writes to a stream object via `stream_write`, so encoders/muxers that
+/// expect a byte sink (e.g. an MP4 fragmenter's output) can write directly
+/// into a NORM stream.
+///
+/// `flush` maps to a passive `stream_flush`. When the stream's send buffer is
+/// full, `NormStreamWrite` accepts zero bytes; this is surfaced as
+/// `io::ErrorKind::WouldBlock` so the writer composes with buffered writers
+/// instead of silently dropping data.
+impl io::Write for Object {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.stream_write(buf).map_err(object_io_error)?;
+
+        if written == 0 && !buf.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::WouldBlock, "NORM stream send buffer is full"));
+        }
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stream_flush(false, FlushMode::Passive).map_err(object_io_error)
+    }
+}
+
+/// Reads from a stream object via `stream_read`.
+///
+/// `NormStreamRead` can succeed with zero bytes when no data is currently
+/// available; this is surfaced as `io::ErrorKind::WouldBlock` rather than a
+/// spurious end-of-stream so callers can retry after the next
+/// `RxObjectUpdated` event.
+impl io::Read for Object {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let bytes_read = self.stream_read(buf).map_err(object_io_error)?;
+
+        if bytes_read == 0 && !buf.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::WouldBlock, "no stream data currently available"));
+        }
+
+        Ok(bytes_read)
+    }
+}
+
+/// Map a NORM `Error` onto the closest `std::io::Error` kind.
+pub(crate) fn object_io_error(err: Error) -> io::Error {
+    match err {
+        Error::InvalidParameter => io::Error::new(io::ErrorKind::InvalidInput, "not a stream object"),
+        other => io::Error::new(io::ErrorKind::Other, format!("{:?}", other)),
+    }
+}
+
+/// A convenience reader over a NORM stream object that surfaces
+/// end-of-message boundaries.
+///
+/// Plain [`Read`](io::Read) reads stop short at a message boundary (a short
+/// read), matching how most byte-oriented decoders expect to be driven.
+/// [`StreamReader::read_message`] is a higher-level alternative that collects
+/// one full message at a time.
+pub struct StreamReader<'a> {
+    object: &'a Object,
+}
+
+impl<'a> StreamReader<'a> {
+    /// Wrap a stream object for reading.
+    pub fn new(object: &'a Object) -> Self {
+        StreamReader { object }
+    }
+
+    /// Read one complete message, resynchronizing to the next message start first.
+    ///
+    /// Calls `stream_seek_msg_start` before reading so a receiver that lost
+    /// part of a previous message realigns to a clean boundary rather than
+    /// returning corrupt framing. A zero-byte `stream_read` only means "no
+    /// data currently available" -- not end-of-message -- so it backs off and
+    /// retries rather than returning a truncated message; it only stops once
+    /// `stream_seek_msg_start` reports the *next* message's start is already
+    /// reachable, which it can only do once every byte of the current one has
+    /// arrived.
+    ///
+    /// # Errors
+    /// Returns an error if this is not a stream object, or if the underlying
+    /// read fails.
+    pub fn read_message(&mut self) -> Result<Vec<u8>> {
+        self.object.stream_seek_msg_start()?;
+
+        let mut message = Vec::new();
+        let mut chunk = [0u8; 4096];
+
+        loop {
+            let bytes_read = self.object.stream_read(&mut chunk)?;
+            if bytes_read > 0 {
+                message.extend_from_slice(&chunk[..bytes_read]);
+                continue;
+            }
+
+            if self.object.stream_seek_msg_start()? {
+                return Ok(message);
+            }
+            std::thread::sleep(STREAM_VACANCY_POLL_INTERVAL);
+        }
+    }
+}
+
+impl<'a> io::Read for StreamReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let bytes_read = self.object.stream_read(buf).map_err(object_io_error)?;
+
+        if bytes_read == 0 && !buf.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::WouldBlock, "no stream data currently available"));
+        }
+
+        Ok(bytes_read)
+    }
+}
+
+/// A `std::io::Write` adapter over a stream object opened for sending on a
+/// [`Session`].
+///
+/// Writing delegates to [`Object`]'s `io::Write` impl; this wrapper
+/// additionally keeps the owning session reachable and exposes `mark_eom`/
+/// `close`, which map onto `NormStreamMarkEom`/`NormStreamClose` and have no
+/// `io::Write` equivalent.
+pub struct NormStreamWriter<'s> {
+    session: &'s Session,
+    object: Object,
+}
+
+impl<'s> NormStreamWriter<'s> {
+    /// Wrap a stream object opened for sending on `session`
+    pub fn new(session: &'s Session, object: Object) -> Self {
+        NormStreamWriter { session, object }
+    }
+
+    /// The session this stream was opened on
+    pub fn session(&self) -> &Session {
+        self.session
+    }
+
+    /// The underlying stream object
+    pub fn object(&self) -> &Object {
+        &self.object
+    }
+
+    /// Mark end-of-message, so a reader's `stream_seek_msg_start` resynchronizes here
+    pub fn mark_eom(&self) -> Result<()> {
+        self.object.stream_mark_eom()
+    }
+
+    /// Close the stream
+    ///
+    /// # Arguments
+    /// * `graceful` - Whether to wait for pending data to be delivered before closing
+    pub fn close(&self, graceful: bool) -> Result<()> {
+        self.object.stream_close(graceful)
+    }
+}
+
+impl<'s> io::Write for NormStreamWriter<'s> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.object.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        io::Write::flush(&mut self.object)
+    }
+}
+
+/// A `std::io::Read` adapter over a stream object opened for receiving on a
+/// [`Session`].
+///
+/// Reading delegates to [`Object`]'s `io::Read` impl, which reports
+/// transiently-empty reads as `io::ErrorKind::WouldBlock`. True end-of-stream
+/// (the sender has closed and no more data will ever arrive) is not
+/// distinguishable from that at the object level; watch for
+/// `EventType::RxObjectCompleted`/`RemoteSenderInactive` on the owning
+/// session's event loop to tell the two apart.
+pub struct NormStreamReader<'s> {
+    session: &'s Session,
+    object: Object,
+}
+
+impl<'s> NormStreamReader<'s> {
+    /// Wrap a stream object opened for receiving on `session`
+    pub fn new(session: &'s Session, object: Object) -> Self {
+        NormStreamReader { session, object }
+    }
+
+    /// The session this stream was opened on
+    pub fn session(&self) -> &Session {
+        self.session
+    }
+
+    /// The underlying stream object
+    pub fn object(&self) -> &Object {
+        &self.object
+    }
+}
+
+impl<'s> io::Read for NormStreamReader<'s> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.object.read(buf)
+    }
+}
+
+/// Datagram-like semantics on top of a NORM stream's reliable byte transport.
+///
+/// Wraps a stream [`Object`] so callers send and receive discrete messages
+/// instead of hand-rolling the `stream_write`/`stream_mark_eom`/
+/// `stream_flush` and `stream_seek_msg_start`/`stream_read` dance themselves.
+/// Works for either direction: use [`MessageStream::send_message`] on an
+/// object opened for sending, [`MessageStream::recv_message`] on one opened
+/// for receiving.
+pub struct MessageStream {
+    object: Object,
+}
+
+impl MessageStream {
+    /// Wrap a stream object for message-oriented send/receive
+    pub fn new(object: Object) -> Self {
+        MessageStream { object }
+    }
+
+    /// The underlying stream object
+    pub fn object(&self) -> &Object {
+        &self.object
+    }
+
+    /// Send `data` as a single discrete message: write it, mark end-of-message,
+    /// then passively flush.
+    ///
+    /// Backs off with a short sleep whenever the stream has no vacancy,
+    /// rather than busy-spinning `stream_write` calls against a full send
+    /// buffer while waiting for it to drain.
+    ///
+    /// # Errors
+    /// Returns an error if this is not a stream object, or if the underlying
+    /// write fails.
+    pub fn send_message(&self, data: &[u8]) -> Result<()> {
+        let mut sent = 0;
+        while sent < data.len() {
+            if !self.object.stream_has_vacancy()? {
+                std::thread::sleep(STREAM_VACANCY_POLL_INTERVAL);
+                continue;
+            }
+
+            let n = self.object.stream_write(&data[sent..])?;
+            if n == 0 {
+                std::thread::sleep(STREAM_VACANCY_POLL_INTERVAL);
+                continue;
+            }
+            sent += n;
+        }
+
+        self.object.stream_mark_eom()?;
+        self.object.stream_flush(false, FlushMode::Passive)
+    }
+
+    /// Receive the next discrete message.
+    ///
+    /// Resynchronizes to the next message start before reading, so a
+    /// receiver that lost part of a previous message realigns to a clean
+    /// boundary rather than returning corrupt framing.
+    ///
+    /// # Errors
+    /// Returns an error if this is not a stream object, or if the underlying
+    /// read fails.
+    pub fn recv_message(&mut self) -> Result<Vec<u8>> {
+        StreamReader::new(&self.object).read_message()
+    }
 }
\ No newline at end of file
@@ -0,0 +1,122 @@
+//! Object reception progress tracking: throughput, ETA, and completion fraction.
+//!
+//! [`ProgressTracker`] wraps one object's progress, fed by the `RxObjectUpdated`/
+//! `RxObjectCompleted` events for that object. It follows the same min/value/max
+//! model as a typical progress bar: [`ProgressTracker::completed_bytes`]/
+//! [`ProgressTracker::total_bytes`] are the raw counters, and
+//! [`ProgressTracker::fraction`] derives the `[0.0, 1.0]` ratio -- `None` for
+//! objects with no well-known total, like streams.
+
+use crate::object::Object;
+use crate::types::ObjectType;
+use std::time::{Duration, Instant};
+
+/// How much weight `update`'s instantaneous rate carries in the throughput EWMA.
+const EWMA_ALPHA: f64 = 0.3;
+
+/// Tracks reception progress, throughput, and ETA for one object.
+#[derive(Debug)]
+pub struct ProgressTracker {
+    total_bytes: Option<u64>,
+    completed_bytes: u64,
+    throughput: f64,
+    last_update: Option<Instant>,
+    done: bool,
+}
+
+impl ProgressTracker {
+    /// Start tracking `object`.
+    ///
+    /// Reads `object`'s total size up front; stream objects have no
+    /// well-known total, so `total_bytes`/`fraction`/`eta` report `None` for them.
+    pub fn new(object: &Object) -> Self {
+        let total_bytes = match object.get_type() {
+            ObjectType::Stream | ObjectType::None => None,
+            _ => Some(object.size().max(0) as u64),
+        };
+
+        ProgressTracker {
+            total_bytes,
+            completed_bytes: 0,
+            throughput: 0.0,
+            last_update: None,
+            done: false,
+        }
+    }
+
+    /// Record an `RxObjectUpdated` for the tracked object.
+    ///
+    /// Updates the throughput EWMA from the elapsed monotonic time and the
+    /// newly-completed bytes since the last update.
+    pub fn update(&mut self, object: &Object) {
+        let completed = (object.size() - object.bytes_pending()).max(0) as u64;
+        self.observe(completed);
+    }
+
+    /// Record an `RxObjectCompleted` for the tracked object.
+    ///
+    /// Clamps `fraction()` to `1.0` and `eta()` to `Some(Duration::ZERO)`
+    /// regardless of the object's last reported byte counts.
+    pub fn complete(&mut self) {
+        if let Some(total) = self.total_bytes {
+            self.observe(total);
+        }
+        self.done = true;
+    }
+
+    fn observe(&mut self, completed_bytes: u64) {
+        let now = Instant::now();
+        if let Some(last) = self.last_update {
+            let elapsed = now.duration_since(last).as_secs_f64();
+            if elapsed > 0.0 {
+                let delta = completed_bytes.saturating_sub(self.completed_bytes) as f64;
+                let instant_rate = delta / elapsed;
+                self.throughput = EWMA_ALPHA * instant_rate + (1.0 - EWMA_ALPHA) * self.throughput;
+            }
+        }
+        self.completed_bytes = completed_bytes;
+        self.last_update = Some(now);
+    }
+
+    /// Bytes received so far
+    pub fn completed_bytes(&self) -> u64 {
+        self.completed_bytes
+    }
+
+    /// Total object size, or `None` for objects with no well-known total (streams)
+    pub fn total_bytes(&self) -> Option<u64> {
+        self.total_bytes
+    }
+
+    /// Current throughput estimate in bytes/sec, an exponentially-weighted
+    /// moving average updated on each `update()`
+    pub fn throughput(&self) -> f64 {
+        self.throughput
+    }
+
+    /// Fraction complete in `[0.0, 1.0]`, or `None` for objects with no
+    /// well-known total. Clamped to `1.0` once `complete()` has been called.
+    pub fn fraction(&self) -> Option<f64> {
+        let total = self.total_bytes?;
+        if self.done || total == 0 {
+            return Some(1.0);
+        }
+        Some((self.completed_bytes as f64 / total as f64).min(1.0))
+    }
+
+    /// Estimated time remaining, or `None` for objects with no well-known
+    /// total, or while the throughput estimate is still zero (guarding
+    /// against a division by zero before the first `update()`).
+    pub fn eta(&self) -> Option<Duration> {
+        let total = self.total_bytes?;
+        if self.done {
+            return Some(Duration::ZERO);
+        }
+        if self.throughput <= 0.0 {
+            return None;
+        }
+
+        let remaining = total.saturating_sub(self.completed_bytes) as f64;
+        Some(Duration::from_secs_f64(remaining / self.throughput))
+    }
+}
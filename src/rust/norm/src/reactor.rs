@@ -0,0 +1,197 @@
+//! Async event stream integration for tokio, behind the `tokio` feature.
+//!
+//! NORM exposes a single pollable descriptor per instance via `NormGetDescriptor`
+//! that becomes readable whenever protocol events are queued. This module wraps
+//! that descriptor in `tokio::io::unix::AsyncFd` and drains events with the
+//! non-blocking [`Instance::next_event`] call, yielding each decoded `Event` as
+//! a `Stream` so a sender can await `TxFlushCompleted` or a receiver can await
+//! `RxObjectCompleted` inside a normal async task, alongside sockets, timers,
+//! and channels. [`NormEventStream`] is `Send` so it can be driven from a
+//! spawned task rather than only the task that created it.
+//!
+//! [`AsyncStreamReader`] and [`AsyncStreamWriter`] adapt a stream [`Object`]
+//! to `AsyncRead`/`AsyncWrite` the same way, but since an instance only has
+//! one descriptor and a reactor can't register it twice, they're built from
+//! a caller-owned [`NormEventStream`] rather than the `Instance` directly.
+
+use crate::event::Event;
+use crate::instance::Instance;
+use crate::object::{Object, object_io_error};
+use crate::types::FlushMode;
+use futures_core::Stream;
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tokio::io::unix::AsyncFd;
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+/// A minimal `AsRawFd` handle over a borrowed NORM instance descriptor.
+///
+/// This does not own or close the descriptor; its lifetime is tied to the
+/// `Instance` that produced it.
+#[derive(Debug, Clone, Copy)]
+struct BorrowedDescriptor(RawFd);
+
+impl AsRawFd for BorrowedDescriptor {
+    fn as_raw_fd(&self) -> RawFd {
+        self.0
+    }
+}
+
+/// An async stream of NORM events, backed by the instance's readiness descriptor.
+///
+/// Borrows the [`Instance`] for its lifetime. Only one `NormEventStream` should be
+/// driven per instance at a time: NORM coalesces readiness across all queued
+/// events rather than signaling per-event. The same restriction extends to
+/// [`AsyncStreamReader`]/[`AsyncStreamWriter`] -- a raw fd can only be
+/// registered with tokio's reactor once, so they borrow this stream's
+/// already-registered `AsyncFd` rather than registering the instance
+/// descriptor again.
+pub struct NormEventStream<'a> {
+    instance: &'a Instance,
+    async_fd: AsyncFd<BorrowedDescriptor>,
+}
+
+impl<'a> NormEventStream<'a> {
+    pub(crate) fn new(instance: &'a Instance) -> std::io::Result<Self> {
+        let async_fd = AsyncFd::new(BorrowedDescriptor(instance.descriptor()))?;
+        Ok(NormEventStream { instance, async_fd })
+    }
+}
+
+impl<'a> Stream for NormEventStream<'a> {
+    type Item = Event;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            let mut guard = match this.async_fd.poll_read_ready(cx) {
+                Poll::Ready(Ok(guard)) => guard,
+                // The descriptor was closed out from under us; end the stream.
+                Poll::Ready(Err(_)) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            // Always drain with the non-blocking call: NORM only signals
+            // readiness once per burst, so leaving events behind here would
+            // strand them until the next unrelated readiness edge.
+            match this.instance.next_event(false) {
+                Ok(Some(event)) => return Poll::Ready(Some(event)),
+                Ok(None) => {
+                    guard.clear_ready();
+                    // Fall through and re-register for the next readiness edge.
+                }
+                Err(_) => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+// SAFETY: `NormEventStream` never exposes interior mutability that would let
+// two threads call into the borrowed `Instance` through it concurrently --
+// polling always happens from whichever single thread currently owns the
+// stream, so moving it (and the `&Instance` it holds) to another thread is
+// safe on its own. As with any other NORM handle, the caller must still
+// avoid driving other calls into the same `Instance` from a second thread
+// while this stream is live (see `shared` for the fully-synchronized case).
+unsafe impl<'a> Send for NormEventStream<'a> {}
+
+/// An `AsyncRead` adapter over a stream [`Object`], backed by the same
+/// registered descriptor as the caller's [`NormEventStream`].
+///
+/// A raw fd can only be registered with a reactor once, so this borrows the
+/// `AsyncFd` an already-constructed `NormEventStream` holds instead of
+/// registering the instance descriptor a second time (which would fail with
+/// `EEXIST`); driving `Instance::event_stream()` and a stream reader/writer on the
+/// same instance therefore requires routing both through one `NormEventStream`.
+///
+/// `NormStreamRead` yielding zero bytes (the would-block case) is treated as
+/// "not ready": the descriptor's readiness is cleared and the task parks
+/// until the next readiness edge, which fires on `RxObjectUpdated` (and
+/// other protocol activity) alongside everything else NORM signals on.
+pub struct AsyncStreamReader<'a> {
+    object: Object,
+    async_fd: &'a AsyncFd<BorrowedDescriptor>,
+}
+
+impl<'a> AsyncStreamReader<'a> {
+    /// Wrap a stream object for async reading, driven by `events`' already-registered descriptor.
+    pub fn new(events: &'a NormEventStream<'_>, object: Object) -> Self {
+        AsyncStreamReader { object, async_fd: &events.async_fd }
+    }
+}
+
+impl<'a> AsyncRead for AsyncStreamReader<'a> {
+    fn poll_read(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+
+        loop {
+            let mut guard = match this.async_fd.poll_read_ready(cx) {
+                Poll::Ready(Ok(guard)) => guard,
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            match this.object.stream_read(buf.initialize_unfilled()) {
+                Ok(0) => guard.clear_ready(),
+                Ok(n) => {
+                    buf.advance(n);
+                    return Poll::Ready(Ok(()));
+                }
+                Err(e) => return Poll::Ready(Err(object_io_error(e))),
+            }
+        }
+    }
+}
+
+/// An `AsyncWrite` adapter over a stream [`Object`], backed by the same
+/// registered descriptor as the caller's [`NormEventStream`] (see
+/// [`AsyncStreamReader`] for why this borrows rather than registers anew).
+///
+/// Writes park on `stream_has_vacancy` returning `false` rather than
+/// accepting (and silently dropping) a short write: the task re-checks
+/// vacancy each time the instance descriptor signals readiness, since NORM
+/// has no dedicated "send buffer drained" readiness of its own.
+pub struct AsyncStreamWriter<'a> {
+    object: Object,
+    async_fd: &'a AsyncFd<BorrowedDescriptor>,
+}
+
+impl<'a> AsyncStreamWriter<'a> {
+    /// Wrap a stream object for async writing, driven by `events`' already-registered descriptor.
+    pub fn new(events: &'a NormEventStream<'_>, object: Object) -> Self {
+        AsyncStreamWriter { object, async_fd: &events.async_fd }
+    }
+}
+
+impl<'a> AsyncWrite for AsyncStreamWriter<'a> {
+    fn poll_write(self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &[u8]) -> Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+
+        loop {
+            let has_vacancy = this.object.stream_has_vacancy().map_err(object_io_error)?;
+            if !has_vacancy {
+                let mut guard = match this.async_fd.poll_read_ready(cx) {
+                    Poll::Ready(Ok(guard)) => guard,
+                    Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                    Poll::Pending => return Poll::Pending,
+                };
+                guard.clear_ready();
+                continue;
+            }
+
+            return Poll::Ready(this.object.stream_write(buf).map_err(object_io_error));
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        Poll::Ready(this.object.stream_flush(false, FlushMode::Passive).map_err(object_io_error))
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        Poll::Ready(this.object.stream_close(true).map_err(object_io_error))
+    }
+}
@@ -0,0 +1,321 @@
+//! Request/response RPC layer over NORM objects, behind the `tokio` feature.
+//!
+//! Each call is assigned a [`RequestId`] combining the calling session's
+//! local node id (upper 32 bits) with a per-endpoint monotonic counter
+//! (lower 32 bits) -- a NORM session is inherently broadcast, so two peers
+//! each placing their first call would otherwise both mint id `1` and could
+//! resolve each other's in-flight calls with their own unrelated responses.
+//! The request body is sent as a NORM data object with this id and the
+//! method name packed into the object's info field (the same field
+//! [`Object::has_info`]/[`Object::get_info`] already surface), optionally
+//! paired with a stream object carrying a larger, incremental body -- the
+//! stream's own info field carries just the request id (see
+//! [`encode_stream_info`]), since NORM only lets a stream's info be set once,
+//! at [`Session::stream_open`] time. A stream observed via `RxObjectInfo`
+//! before its data object completes is held in `pending_streams` until the
+//! matching request/response arrives to attach it to.
+//! Responses are matched back to their caller through an `inflight` map
+//! keyed by the same id, echoed back in the response's info field -- the
+//! multiplexing scheme a single NORM session needs to carry many concurrent
+//! calls to many peers over its one event stream.
+//!
+//! [`RpcEndpoint`] is driven by feeding it every event observed on the
+//! owning session: [`RpcEndpoint::dispatch`] resolves a matching `call`, or
+//! invokes a handler registered with [`RpcEndpoint::register`] and sends its
+//! response.
+
+use crate::error::{Error, Result};
+use crate::event::Event;
+use crate::node::Node;
+use crate::object::{Object, STREAM_VACANCY_POLL_INTERVAL};
+use crate::session::Session;
+use crate::types::{EventType, FlushMode, ObjectType, RequestId};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+use tokio::sync::oneshot;
+
+/// Buffer size for a stream object opened by [`RpcEndpoint::call_with_stream`].
+const STREAM_BUFFER_SIZE: u32 = 1024 * 1024;
+
+/// A handler invoked for an incoming [`Request`], producing the response body.
+pub type Handler = Box<dyn Fn(&Request) -> Result<Vec<u8>> + Send + Sync>;
+
+/// An RPC response, matched back to the call that produced it.
+#[derive(Debug)]
+pub struct Response {
+    /// The method that was called
+    pub method: String,
+    /// The response payload
+    pub body: Vec<u8>,
+    /// An associated streaming body, if the call carried one
+    pub stream: Option<Object>,
+}
+
+/// An incoming RPC request delivered to a registered [`Handler`].
+#[derive(Debug)]
+pub struct Request {
+    /// The node that placed the call
+    pub from: Node,
+    /// The requested method name
+    pub method: String,
+    /// The request payload
+    pub body: Vec<u8>,
+    /// An associated streaming body, if the call carried one
+    pub stream: Option<Object>,
+}
+
+/// RPC client and server state layered over a [`Session`].
+///
+/// One endpoint multiplexes any number of concurrent [`RpcEndpoint::call`]s
+/// and any number of registered [`Handler`]s over the session's single
+/// stream of events; nothing here reads events itself; the owner's event
+/// loop must pass every event it sees to [`RpcEndpoint::dispatch`].
+pub struct RpcEndpoint<'s> {
+    session: &'s Session,
+    next_id: AtomicU32,
+    inflight: Mutex<HashMap<RequestId, oneshot::Sender<Response>>>,
+    handlers: Mutex<HashMap<String, Handler>>,
+    /// Stream objects observed (via `RxObjectInfo`) before the data object
+    /// carrying the same request id completed, held until `dispatch` can
+    /// attach them to the `Request`/`Response` they belong to.
+    pending_streams: Mutex<HashMap<RequestId, Object>>,
+}
+
+impl<'s> RpcEndpoint<'s> {
+    /// Create an endpoint over `session`, with no handlers registered.
+    pub fn new(session: &'s Session) -> Self {
+        RpcEndpoint {
+            session,
+            next_id: AtomicU32::new(1),
+            inflight: Mutex::new(HashMap::new()),
+            handlers: Mutex::new(HashMap::new()),
+            pending_streams: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Mint a request id unique to this endpoint's session across the whole
+    /// (broadcast) session: the local node id in the upper 32 bits rules out
+    /// collisions with any other peer's counter.
+    fn next_request_id(&self) -> RequestId {
+        let counter = self.next_id.fetch_add(1, Ordering::Relaxed);
+        ((self.session.local_node_id() as RequestId) << 32) | counter as RequestId
+    }
+
+    /// Register a handler for `method`, replacing any prior handler of the same name.
+    pub fn register<F>(&self, method: impl Into<String>, handler: F)
+    where
+        F: Fn(&Request) -> Result<Vec<u8>> + Send + Sync + 'static,
+    {
+        self.handlers.lock().unwrap().insert(method.into(), Box::new(handler));
+    }
+
+    /// Call `method` with `body`, awaiting its response.
+    ///
+    /// The call is broadcast over the session like any other NORM object;
+    /// only a peer with a matching registered handler responds. This is the
+    /// unary form; see [`RpcEndpoint::call_with_stream`] to attach an
+    /// incremental streaming body.
+    ///
+    /// # Errors
+    /// Returns an error if the request could not be enqueued, or if the
+    /// endpoint is dropped before a response arrives.
+    pub async fn call(&self, method: &str, body: &[u8]) -> Result<Response> {
+        self.call_with_stream(method, body, None).await
+    }
+
+    /// Call `method` with `body`, attaching `stream_body` as an incremental
+    /// body alongside the unary request, and await the response.
+    ///
+    /// The stream is opened here (rather than accepted pre-opened) because
+    /// its info -- carrying the request id so the far end can pair it with
+    /// the unary request -- can only be set once, at `stream_open` time.
+    ///
+    /// # Errors
+    /// Returns an error if the request or stream object could not be
+    /// enqueued, or if the endpoint is dropped before a response arrives.
+    pub async fn call_with_stream(
+        &self,
+        method: &str,
+        body: &[u8],
+        stream_body: Option<&[u8]>,
+    ) -> Result<Response> {
+        let request_id = self.next_request_id();
+        let (tx, rx) = oneshot::channel();
+        self.inflight.lock().unwrap().insert(request_id, tx);
+
+        let info = encode_info(request_id, method);
+        if let Err(e) = self.session.data_enqueue(body, Some(&info)) {
+            self.inflight.lock().unwrap().remove(&request_id);
+            return Err(e);
+        }
+
+        if let Some(stream_body) = stream_body {
+            if let Err(e) = self.send_stream(request_id, stream_body) {
+                self.inflight.lock().unwrap().remove(&request_id);
+                return Err(e);
+            }
+        }
+
+        rx.await
+            .map_err(|_| Error::OperationFailed("RPC endpoint dropped before response arrived".to_string()))
+    }
+
+    /// Open a stream carrying `request_id` in its info field and write `body`
+    /// to it, marking end-of-message once everything has been sent.
+    fn send_stream(&self, request_id: RequestId, body: &[u8]) -> Result<()> {
+        let info = encode_stream_info(request_id);
+        let stream = self.session.stream_open(STREAM_BUFFER_SIZE, Some(&info))?;
+
+        let mut sent = 0;
+        while sent < body.len() {
+            if !stream.stream_has_vacancy()? {
+                std::thread::sleep(STREAM_VACANCY_POLL_INTERVAL);
+                continue;
+            }
+
+            let n = stream.stream_write(&body[sent..])?;
+            if n == 0 {
+                std::thread::sleep(STREAM_VACANCY_POLL_INTERVAL);
+                continue;
+            }
+            sent += n;
+        }
+
+        stream.stream_mark_eom()?;
+        stream.stream_flush(false, FlushMode::Passive)
+    }
+
+    /// Feed one event observed on the owning session's event loop into the endpoint.
+    ///
+    /// A `Stream`-type object is only ever a side-channel on a `call_with_stream`
+    /// call: its `RxObjectInfo` event carries its request id, and it's held in
+    /// `pending_streams` until the data object for that same request id shows
+    /// up. Resolves a matching in-flight `call` on its `RxObjectCompleted`, or
+    /// invokes the registered handler for the object's method and sends its
+    /// response. Events for objects carrying an id this endpoint has no
+    /// record of (neither an in-flight call nor a registered handler for the
+    /// method) are ignored, since the same session may carry unrelated
+    /// traffic.
+    ///
+    /// # Errors
+    /// Returns an error if a matched response could not be sent.
+    pub fn dispatch(&self, event: &Event) -> Result<()> {
+        let object = Object::from_handle_unowned(event.object);
+
+        if event.event_type == EventType::RxObjectInfo && object.get_type() == ObjectType::Stream {
+            if object.has_info() {
+                if let Ok(info) = object.get_info() {
+                    if let Some(request_id) = decode_stream_info(&info) {
+                        self.pending_streams.lock().unwrap().insert(request_id, object);
+                    }
+                }
+            }
+            return Ok(());
+        }
+
+        if event.event_type != EventType::RxObjectCompleted || object.get_type() != ObjectType::Data || !object.has_info() {
+            return Ok(());
+        }
+
+        let info = object.get_info()?;
+        let Some((request_id, method)) = decode_info(&info) else {
+            return Ok(());
+        };
+        let body = object.access_data()?.to_vec();
+        let stream = self.pending_streams.lock().unwrap().remove(&request_id);
+
+        if let Some(tx) = self.inflight.lock().unwrap().remove(&request_id) {
+            let _ = tx.send(Response { method, body, stream });
+            return Ok(());
+        }
+
+        let handler = { self.handlers.lock().unwrap().get(&method).map(|_| ()) };
+        if handler.is_none() {
+            return Ok(());
+        }
+
+        let from = object.get_sender()?;
+        let request = Request { from, method, body, stream };
+
+        let reply = {
+            let handlers = self.handlers.lock().unwrap();
+            let handler = handlers.get(&request.method).expect("checked above");
+            handler(&request)?
+        };
+
+        let info = encode_info(request_id, &request.method);
+        self.session.data_enqueue(&reply, Some(&info))?;
+        Ok(())
+    }
+}
+
+fn encode_info(request_id: RequestId, method: &str) -> Vec<u8> {
+    let mut info = Vec::with_capacity(8 + method.len());
+    info.extend_from_slice(&request_id.to_be_bytes());
+    info.extend_from_slice(method.as_bytes());
+    info
+}
+
+fn decode_info(info: &[u8]) -> Option<(RequestId, String)> {
+    if info.len() < 8 {
+        return None;
+    }
+    let request_id = RequestId::from_be_bytes(info[..8].try_into().ok()?);
+    let method = String::from_utf8(info[8..].to_vec()).ok()?;
+    Some((request_id, method))
+}
+
+/// Encode a stream object's info field: just the request id it belongs to,
+/// with no method name (the method is only carried on the unary data object).
+fn encode_stream_info(request_id: RequestId) -> Vec<u8> {
+    request_id.to_be_bytes().to_vec()
+}
+
+fn decode_stream_info(info: &[u8]) -> Option<RequestId> {
+    Some(RequestId::from_be_bytes(info.get(..8)?.try_into().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode_info_round_trip() {
+        let info = encode_info(0x0001_0002_0000_002a, "echo");
+        assert_eq!(decode_info(&info), Some((0x0001_0002_0000_002a, "echo".to_string())));
+    }
+
+    #[test]
+    fn test_decode_info_rejects_short_buffer() {
+        assert_eq!(decode_info(&[0u8; 7]), None);
+    }
+
+    #[test]
+    fn test_decode_info_empty_method() {
+        let info = encode_info(42, "");
+        assert_eq!(decode_info(&info), Some((42, String::new())));
+    }
+
+    #[test]
+    fn test_encode_decode_stream_info_round_trip() {
+        let info = encode_stream_info(0x0001_0002_0000_002a);
+        assert_eq!(decode_stream_info(&info), Some(0x0001_0002_0000_002a));
+    }
+
+    #[test]
+    fn test_decode_stream_info_rejects_short_buffer() {
+        assert_eq!(decode_stream_info(&[0u8; 7]), None);
+    }
+
+    #[test]
+    fn test_request_ids_from_different_nodes_never_collide() {
+        // Mirrors `RpcEndpoint::next_request_id`'s composition: local node id
+        // in the upper 32 bits, per-endpoint counter in the lower 32 -- two
+        // peers starting their counter at the same value must still mint
+        // distinct ids.
+        let id_from_node_a = ((1u32 as RequestId) << 32) | 1;
+        let id_from_node_b = ((2u32 as RequestId) << 32) | 1;
+        assert_ne!(id_from_node_a, id_from_node_b);
+    }
+}
@@ -2,6 +2,7 @@ use crate::error::{Error, Result, bool_result, check_handle, string_to_c_string}
 use crate::types::*;
 use crate::object::Object;
 use norm_sys::*;
+use std::cell::RefCell;
 use std::os::raw::c_char;
 use std::ptr;
 
@@ -15,6 +16,9 @@ use std::ptr;
 pub struct Session {
     /// The raw NORM session handle
     handle: NormSessionHandle,
+    /// Nodes registered via `add_acking_node`, tracked locally since NORM has
+    /// no API to enumerate them back out
+    acking_nodes: RefCell<Vec<NodeId>>,
 }
 
 impl Session {
@@ -42,7 +46,7 @@ impl Session {
         };
 
         unsafe { check_handle(handle, NORM_SESSION_INVALID)? };
-        Ok(Session { handle })
+        Ok(Session { handle, acking_nodes: RefCell::new(Vec::new()) })
     }
 
     /// Start the session as a NORM sender
@@ -88,6 +92,45 @@ impl Session {
         self
     }
 
+    /// Begin building a sender configuration
+    ///
+    /// This is the preferred way to start a sender: named, chainable setters
+    /// replace the fragile positional `start_sender` arguments and surface
+    /// FEC tuning (codec selection, auto parity, group size) that the plain
+    /// call hides.
+    ///
+    /// # Returns
+    /// A [`SenderConfig`] builder; call [`SenderConfig::start`] to apply it
+    pub fn sender(&self) -> SenderConfig<'_> {
+        SenderConfig::new(self)
+    }
+
+    /// Set the number of proactively-transmitted parity segments per block
+    ///
+    /// Unlike reactive repair (triggered by NACKs), auto parity segments are
+    /// sent up front with every block, trading bandwidth for lower expected
+    /// repair latency on lossy networks.
+    ///
+    /// # Returns
+    /// `Ok(())` on success or an `Err` if auto parity could not be set
+    pub fn set_auto_parity(&self, num_parity: u16) -> Result<&Self> {
+        let success = unsafe { NormSetAutoParity(self.handle, num_parity) };
+        bool_result(success, "Failed to set auto parity")?;
+        Ok(self)
+    }
+
+    /// Set the sender's estimate of the multicast group size
+    ///
+    /// This tunes NORM's NACK suppression and timer back-off feedback
+    /// algorithms, which scale with the expected number of receivers.
+    ///
+    /// # Returns
+    /// A reference to self for method chaining
+    pub fn set_group_size(&self, group_size: u32) -> &Self {
+        unsafe { NormSetGroupSize(self.handle, group_size) };
+        self
+    }
+
     /// Start the session as a NORM receiver
     ///
     /// # Arguments
@@ -178,6 +221,24 @@ impl Session {
         self
     }
 
+    /// Enable or disable Explicit Congestion Notification (ECN) support
+    ///
+    /// When enabled, NORM treats ECN-marked packets as an early congestion
+    /// signal alongside loss feedback, letting congestion control back off
+    /// before receivers actually start dropping data.
+    ///
+    /// # Arguments
+    /// * `enable` - Whether to enable ECN support
+    /// * `ignore_loss` - Whether to ignore loss events when ECN marking is present
+    /// * `tolerate_loss` - Whether to tolerate some loss without treating it as congestion
+    ///
+    /// # Returns
+    /// A reference to self for method chaining
+    pub fn set_ecn_support(&self, enable: bool, ignore_loss: bool, tolerate_loss: bool) -> &Self {
+        unsafe { NormSetEcnSupport(self.handle, enable, ignore_loss, tolerate_loss) };
+        self
+    }
+
     /// Set the transmission rate bounds
     ///
     /// # Arguments
@@ -191,6 +252,39 @@ impl Session {
         self
     }
 
+    /// Apply a typed congestion-control configuration.
+    ///
+    /// Replaces picking `set_congestion_control`/`set_tx_rate_bounds`/
+    /// `set_ecn_support` calls by hand, which makes it easy to leave them in
+    /// a combination NORM doesn't actually support (e.g. ECN with a fixed,
+    /// uncontrolled rate). See [`CongestionControl`] for the modeled modes.
+    ///
+    /// # Errors
+    /// Returns `Error::InvalidParameter` if `cfg` describes an invalid
+    /// combination (a `TcpFriendly` rate range with `rate_min > rate_max`,
+    /// or `ecn` enabled alongside `Fixed`).
+    pub fn configure_rate_control(&self, cfg: CongestionControl) -> Result<&Self> {
+        cfg.validate()?;
+
+        match cfg.mode {
+            RateControlMode::Fixed { bits_per_second } => {
+                self.set_congestion_control(false, false);
+                self.set_tx_rate(bits_per_second);
+            }
+            RateControlMode::TcpFriendly { rate_min, rate_max, adjust_rate } => {
+                self.set_congestion_control(true, adjust_rate);
+                self.set_tx_rate_bounds(rate_min, rate_max);
+            }
+        }
+
+        match cfg.ecn {
+            Some((ignore_loss, tolerate_loss)) => self.set_ecn_support(true, ignore_loss, tolerate_loss),
+            None => self.set_ecn_support(false, false, false),
+        };
+
+        Ok(self)
+    }
+
     /// Set the multicast interface
     ///
     /// # Arguments
@@ -306,6 +400,39 @@ impl Session {
         unsafe { NormGetGrttEstimate(self.handle) }
     }
 
+    /// Set how often NORM recomputes the metrics returned by [`Session::report`]
+    ///
+    /// # Returns
+    /// A reference to self for method chaining
+    pub fn set_report_interval(&self, interval: std::time::Duration) -> &Self {
+        unsafe { NormSetReportInterval(self.handle, interval.as_secs_f64()) };
+        self
+    }
+
+    /// Get the currently configured reporting interval
+    pub fn report_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_secs_f64(unsafe { NormGetReportInterval(self.handle) })
+    }
+
+    /// Pull a one-shot snapshot of this session's reporting metrics
+    ///
+    /// NORM's public API does not expose the finer-grained per-sender buffer
+    /// usage/loss/NACK counters that appear in its text debug log (see
+    /// [`crate::Instance::open_debug_log`]) as typed getters, so this
+    /// snapshot covers GRTT, transmit rate, and acking-node status; poll it
+    /// on [`Session::report_interval`]'s cadence to track those over time,
+    /// since NORM does not deliver a distinct report event of its own.
+    ///
+    /// # Returns
+    /// A [`SessionReport`] snapshot of the session's current metrics
+    pub fn report(&self) -> SessionReport {
+        SessionReport {
+            grtt_estimate: self.grtt_estimate(),
+            tx_rate: self.tx_rate(),
+            acking_statuses: self.acking_statuses().collect(),
+        }
+    }
+
     /// Enqueue a file for transmission
     ///
     /// # Arguments
@@ -418,6 +545,48 @@ impl Session {
         unsafe { NormCancelWatermark(self.handle) };
     }
 
+    /// Require `node_id` to positively acknowledge the next watermark
+    ///
+    /// Combined with [`Session::set_watermark`], this turns best-effort
+    /// multicast into confirmed delivery to an explicit membership set: poll
+    /// [`Session::acking_status`] (or [`Session::acking_statuses`] for the
+    /// whole set) to find which nodes completed and which need retransmission.
+    ///
+    /// # Errors
+    /// Returns an error if `node_id` could not be added
+    pub fn add_acking_node(&self, node_id: NodeId) -> Result<&Self> {
+        let success = unsafe { NormAddAckingNode(self.handle, node_id) };
+        bool_result(success, "Failed to add acking node")?;
+        self.acking_nodes.borrow_mut().push(node_id);
+        Ok(self)
+    }
+
+    /// Stop requiring acknowledgment from `node_id`
+    pub fn remove_acking_node(&self, node_id: NodeId) -> &Self {
+        unsafe { NormRemoveAckingNode(self.handle, node_id) };
+        self.acking_nodes.borrow_mut().retain(|&id| id != node_id);
+        self
+    }
+
+    /// Get the acknowledgment status of a specific acking node
+    pub fn acking_status(&self, node_id: NodeId) -> AckingStatus {
+        AckingStatus::from(unsafe { NormGetAckingStatus(self.handle, node_id) })
+    }
+
+    /// Get the overall acknowledgment status across every acking node
+    pub fn watermark_status(&self) -> AckingStatus {
+        AckingStatus::from(unsafe { NormGetAckingStatus(self.handle, NORM_NODE_ANY) })
+    }
+
+    /// Iterate over the status of every node registered via [`Session::add_acking_node`]
+    ///
+    /// Lets a caller scan for nodes still `Pending`/`Failure` after a
+    /// watermark and retransmit to them specifically.
+    pub fn acking_statuses(&self) -> impl Iterator<Item = (NodeId, AckingStatus)> + '_ {
+        let nodes = self.acking_nodes.borrow().clone();
+        nodes.into_iter().map(move |node_id| (node_id, self.acking_status(node_id)))
+    }
+
     /// Send a command to all receivers
     ///
     /// # Arguments
@@ -443,6 +612,14 @@ impl Session {
         unsafe { NormCancelCommand(self.handle) };
     }
 
+    /// Get the local node id NORM assigned to this session.
+    ///
+    /// If the session was created with `NORM_NODE_ANY`, this resolves to the
+    /// id NORM actually generated rather than echoing that sentinel back.
+    pub fn local_node_id(&self) -> NodeId {
+        unsafe { NormGetLocalNodeId(self.handle) }
+    }
+
     /// Get the raw NORM session handle
     ///
     /// # Returns
@@ -456,4 +633,239 @@ impl Drop for Session {
     fn drop(&mut self) {
         unsafe { NormDestroySession(self.handle) };
     }
+}
+
+/// A one-shot snapshot of a session's reporting metrics, pulled via [`Session::report`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionReport {
+    /// The current GRTT (Generalized Round Trip Time) estimate, in seconds
+    pub grtt_estimate: f64,
+    /// The current measured/advertised transmit rate, in bits per second
+    pub tx_rate: f64,
+    /// Acknowledgment status of every node registered via [`Session::add_acking_node`]
+    pub acking_statuses: Vec<(NodeId, AckingStatus)>,
+}
+
+/// How a sender should pace its transmission rate.
+///
+/// Passed via [`CongestionControl::mode`] to [`Session::configure_rate_control`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RateControlMode {
+    /// A fixed, uncontrolled transmission rate; disables NORM's congestion control entirely.
+    Fixed {
+        /// The transmission rate, in bits per second
+        bits_per_second: f64,
+    },
+    /// NORM's TCP-friendly equation-based congestion control, bounded to a rate range.
+    TcpFriendly {
+        /// The minimum transmission rate, in bits per second
+        rate_min: f64,
+        /// The maximum transmission rate, in bits per second
+        rate_max: f64,
+        /// Whether congestion control is allowed to actively adjust the send rate
+        adjust_rate: bool,
+    },
+}
+
+/// A validated congestion-control configuration for [`Session::configure_rate_control`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CongestionControl {
+    mode: RateControlMode,
+    ecn: Option<(bool, bool)>,
+}
+
+impl CongestionControl {
+    /// A fixed, uncontrolled transmission rate, with ECN disabled
+    pub fn fixed(bits_per_second: f64) -> Self {
+        CongestionControl { mode: RateControlMode::Fixed { bits_per_second }, ecn: None }
+    }
+
+    /// TCP-friendly rate control bounded to `[rate_min, rate_max]`, with ECN disabled
+    pub fn tcp_friendly(rate_min: f64, rate_max: f64, adjust_rate: bool) -> Self {
+        CongestionControl {
+            mode: RateControlMode::TcpFriendly { rate_min, rate_max, adjust_rate },
+            ecn: None,
+        }
+    }
+
+    /// Enable ECN as an additional early-congestion signal
+    ///
+    /// # Arguments
+    /// * `ignore_loss` - Whether to ignore loss events when ECN marking is present
+    /// * `tolerate_loss` - Whether to tolerate some loss without treating it as congestion
+    pub fn with_ecn(mut self, ignore_loss: bool, tolerate_loss: bool) -> Self {
+        self.ecn = Some((ignore_loss, tolerate_loss));
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        match self.mode {
+            RateControlMode::TcpFriendly { rate_min, rate_max, .. } if rate_min > rate_max => {
+                Err(Error::InvalidParameter)
+            }
+            RateControlMode::Fixed { .. } if self.ecn.is_some() => Err(Error::InvalidParameter),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(all(unix, feature = "tokio"))]
+impl Session {
+    /// Enqueue `data` for transmission and `await` its watermark completion.
+    ///
+    /// This is the async counterpart to [`Session::data_enqueue`]: it sets a
+    /// watermark on the enqueued object and resolves once the corresponding
+    /// `TxWatermarkCompleted` event is observed, i.e. once every receiver has
+    /// acknowledged the data.
+    ///
+    /// `events` must be the caller's own `Instance::event_stream()` -- per
+    /// [`crate::reactor`]'s documented invariant, only one `NormEventStream`
+    /// can be driven per instance at a time, so this consumes from the
+    /// stream the caller already owns and is polling elsewhere rather than
+    /// registering a second, competing one on the same descriptor. Events
+    /// that aren't this call's watermark/error are consumed and discarded
+    /// by simply continuing the loop, so interleaving this with the
+    /// caller's own `while let Some(event) = stream.next().await` handling
+    /// is not safe; call this from the same place the stream is driven.
+    ///
+    /// # Errors
+    /// Returns an error if the data could not be enqueued, the watermark
+    /// could not be set, a `SendError` event arrives first, or the event
+    /// stream ends before the watermark completes.
+    pub async fn send_data<S>(&self, events: &mut S, data: &[u8], info: Option<&[u8]>) -> Result<()>
+    where
+        S: futures_core::Stream<Item = crate::event::Event> + Unpin,
+    {
+        use std::pin::Pin;
+
+        let object = self.data_enqueue(data, info)?;
+        self.set_watermark(&object, true)?;
+
+        loop {
+            let event = std::future::poll_fn(|cx| Pin::new(&mut *events).poll_next(cx)).await;
+            match event {
+                Some(event) if event.event_type == EventType::TxWatermarkCompleted => return Ok(()),
+                Some(event) if event.event_type == EventType::SendError => {
+                    return Err(Error::OperationFailed("send failed before watermark completed".to_string()));
+                }
+                Some(_) => continue,
+                None => return Err(Error::OperationFailed("event stream ended before watermark completed".to_string())),
+            }
+        }
+    }
+}
+
+/// Builder for starting a NORM sender with named, discoverable settings.
+///
+/// Unset fields default to a random session id and sensible buffer/FEC
+/// values, so `session.sender().start()?` is a valid one-liner, while
+/// `session.sender().buffer(4 * 1024 * 1024).segment_size(1400).fec(64, 16).auto_parity(4).start()?`
+/// exposes the reliability knobs that the positional `start_sender` call hides.
+#[derive(Debug)]
+pub struct SenderConfig<'s> {
+    session: &'s Session,
+    session_id: Option<SessionId>,
+    buffer_space: u32,
+    segment_size: u16,
+    num_data: u16,
+    num_parity: u16,
+    fec_id: Option<u8>,
+    auto_parity: Option<u16>,
+    group_size: Option<u32>,
+}
+
+impl<'s> SenderConfig<'s> {
+    pub(crate) fn new(session: &'s Session) -> Self {
+        SenderConfig {
+            session,
+            session_id: None,
+            buffer_space: 1024 * 1024,
+            segment_size: 1400,
+            num_data: 64,
+            num_parity: 16,
+            fec_id: None,
+            auto_parity: None,
+            group_size: None,
+        }
+    }
+
+    /// Set the session/instance id used to start the sender (default: random)
+    pub fn session_id(mut self, id: SessionId) -> Self {
+        self.session_id = Some(id);
+        self
+    }
+
+    /// Set the sender's transmission buffer size in bytes
+    pub fn buffer(mut self, bytes: u32) -> Self {
+        self.buffer_space = bytes;
+        self
+    }
+
+    /// Set the FEC payload segment size in bytes
+    pub fn segment_size(mut self, bytes: u16) -> Self {
+        self.segment_size = bytes;
+        self
+    }
+
+    /// Set the FEC block size (data segments) and parity segment count
+    pub fn fec(mut self, block: u16, parity: u16) -> Self {
+        self.num_data = block;
+        self.num_parity = parity;
+        self
+    }
+
+    /// Select the FEC codec (default: `FecType::ReedSolomon8`)
+    pub fn fec_type(mut self, fec_type: FecType) -> Self {
+        self.fec_id = Some(fec_type.into());
+        self
+    }
+
+    /// Proactively send this many parity segments per block, in addition to
+    /// any reactive repair triggered by NACKs
+    pub fn auto_parity(mut self, num_parity: u16) -> Self {
+        self.auto_parity = Some(num_parity);
+        self
+    }
+
+    /// Set the sender's estimate of the multicast group size
+    pub fn group_size(mut self, size: u32) -> Self {
+        self.group_size = Some(size);
+        self
+    }
+
+    /// Start the sender with the configured settings
+    ///
+    /// # Returns
+    /// The underlying session for further method chaining, or an `Err` if
+    /// the sender could not be started
+    pub fn start(self) -> Result<&'s Session> {
+        let session_id = self.session_id.unwrap_or_else(random_session_id);
+
+        self.session.start_sender(
+            session_id,
+            self.buffer_space,
+            self.segment_size,
+            self.num_data,
+            self.num_parity,
+            self.fec_id,
+        )?;
+
+        if let Some(auto_parity) = self.auto_parity {
+            self.session.set_auto_parity(auto_parity)?;
+        }
+
+        if let Some(group_size) = self.group_size {
+            self.session.set_group_size(group_size);
+        }
+
+        Ok(self.session)
+    }
+}
+
+/// Generate a pseudo-random session id without pulling in a dependency on `rand`
+fn random_session_id() -> SessionId {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    RandomState::new().build_hasher().finish() as SessionId
 }
\ No newline at end of file
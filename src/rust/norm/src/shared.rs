@@ -0,0 +1,131 @@
+//! Thread-safe sharing of [`Instance`]/[`Session`] across threads.
+//!
+//! The NORM C API is not reentrant: concurrent calls into the same instance
+//! from multiple threads are undefined behavior, so [`Instance`] and
+//! [`Session`] are `!Send`/`!Sync` (their raw handles are bare pointers).
+//! Nothing about the underlying handles is actually thread-*affine*, though
+//! -- NORM only requires that calls be serialized, not that they all come
+//! from one thread -- so [`SharedInstance`]/[`SharedSession`] wrap them in
+//! an `Arc<Mutex<_>>` and assert `Send + Sync` on top.
+//!
+//! # Threading contract
+//!
+//! Every call into the wrapped instance/session is made inside a `with`
+//! closure with the internal lock(s) held, and they are released as soon as
+//! that closure returns. The closures are synchronous, so the locks are never
+//! held across an `.await` point, and only for as long as the closure runs:
+//! a thread enqueueing transmit objects through a `SharedSession` and
+//! another thread making occasional polling calls through
+//! `SharedInstance::with` take turns, each waiting out the other's current
+//! closure. [`SharedSession::with`] takes the owning instance's lock before
+//! the session's own, so that turn-taking is real serialization against the
+//! one underlying instance, not two independent mutexes guarding it. Objects/
+//! nodes handed back from closures passed to
+//! [`SharedInstance::with`]/[`SharedSession::with`] borrow the lock and must
+//! not be retained past the closure's return.
+//!
+//! This does rule out [`Instance::event_stream`](crate::instance::Instance::event_stream):
+//! it borrows the `Instance` for as long as the stream is driven, which
+//! `SharedInstance::with` can't hand out since it only exposes `&Instance`
+//! for the duration of one closure call. A `SharedInstance`'s event loop
+//! must instead poll via repeated, short [`SharedInstance::with`] calls
+//! (e.g. `with(|i| i.next_event(false))` on a timer), not a long-lived
+//! `NormEventStream`.
+
+use crate::error::Result;
+use crate::instance::Instance;
+use crate::session::Session;
+use crate::types::NodeId;
+use std::sync::{Arc, Mutex};
+
+/// A reference-counted, `Send + Sync` handle to a shared [`Instance`].
+#[derive(Clone)]
+pub struct SharedInstance {
+    inner: Arc<Mutex<Instance>>,
+}
+
+impl SharedInstance {
+    /// Wrap an existing instance for thread-safe sharing
+    pub fn new(instance: Instance) -> Self {
+        SharedInstance { inner: Arc::new(Mutex::new(instance)) }
+    }
+
+    /// Run a closure with exclusive access to the underlying instance
+    ///
+    /// A lock poisoned by a prior panicking call is recovered and used as
+    /// is, so this does not panic on poisoning.
+    pub fn with<R>(&self, f: impl FnOnce(&Instance) -> R) -> R {
+        let guard = self.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        f(&guard)
+    }
+
+    /// Create a new session on this instance, wrapped for thread-safe sharing
+    ///
+    /// # Errors
+    /// Returns an error if the underlying session could not be created
+    pub fn create_session<A: AsRef<str>>(
+        &self,
+        session_address: A,
+        session_port: u16,
+        local_node_id: NodeId,
+    ) -> Result<SharedSession> {
+        let session = self.with(|instance| instance.create_session(session_address, session_port, local_node_id))?;
+        Ok(SharedSession { instance: self.clone(), session: Arc::new(Mutex::new(session)) })
+    }
+}
+
+// SAFETY: every access to the wrapped `Instance` goes through `inner`'s
+// mutex, which serializes calls into the non-reentrant NORM API. The raw
+// handle itself carries no thread affinity -- NORM only requires that calls
+// not run concurrently, which the mutex already guarantees.
+unsafe impl Send for SharedInstance {}
+unsafe impl Sync for SharedInstance {}
+
+/// A reference-counted, `Send + Sync` handle to a shared [`Session`].
+///
+/// Created via [`SharedInstance::create_session`].
+#[derive(Clone)]
+pub struct SharedSession {
+    instance: SharedInstance,
+    session: Arc<Mutex<Session>>,
+}
+
+impl SharedSession {
+    /// The [`SharedInstance`] this session was created on
+    pub fn instance(&self) -> &SharedInstance {
+        &self.instance
+    }
+
+    /// Run a closure with exclusive access to the underlying session
+    ///
+    /// Takes the owning [`SharedInstance`]'s lock before the session's own,
+    /// so this can't run concurrently with a [`SharedInstance::with`] call
+    /// on the same instance -- both paths ultimately call into the same
+    /// non-reentrant NORM instance, so one mutex alone isn't enough to
+    /// serialize them against each other.
+    ///
+    /// A lock poisoned by a prior panicking call is recovered and used as
+    /// is, so this does not panic on poisoning.
+    pub fn with<R>(&self, f: impl FnOnce(&Session) -> R) -> R {
+        self.instance.with(|_instance| {
+            let guard = self.session.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            f(&guard)
+        })
+    }
+}
+
+// SAFETY: see `SharedInstance` above -- `with` takes `instance`'s mutex
+// before `session`'s own, so calls are serialized against both other
+// `SharedSession::with` calls and `SharedInstance::with` calls on the same
+// instance.
+unsafe impl Send for SharedSession {}
+unsafe impl Sync for SharedSession {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use static_assertions::assert_impl_all;
+
+    assert_impl_all!(SharedInstance: Send, Sync, Clone);
+    assert_impl_all!(SharedSession: Send, Sync, Clone);
+}
@@ -0,0 +1,286 @@
+//! Reconnect/heartbeat supervision driven by remote-sender liveness events.
+//!
+//! Wraps the `RemoteSenderActive`/`RemoteSenderInactive`/`RemoteSenderPurged`/
+//! `LocalSenderClosed` event stream with automatic recovery: stale remote
+//! nodes are re-armed according to a configurable [`ReconnectStrategy`], with
+//! randomized jitter so a lost link across a whole multicast group doesn't
+//! re-arm every receiver in lockstep and trigger a NACK storm.
+
+use crate::error::Result;
+use crate::event::Event;
+use crate::node::Node;
+use crate::session::Session;
+use crate::types::{EventType, NodeId};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// How a [`Supervisor`] should recover a node it has marked stale.
+#[derive(Debug, Clone, Copy)]
+pub enum ReconnectStrategy {
+    /// Always wait the same interval before re-arming
+    FixedInterval(Duration),
+    /// Exponentially increasing backoff, capped at `max`, with +/- `jitter` randomization
+    ExponentialBackoff {
+        /// The initial backoff before the first retry
+        base: Duration,
+        /// The multiplier applied per attempt
+        factor: f64,
+        /// The maximum backoff, regardless of attempt count
+        max: Duration,
+        /// Randomization applied to the computed backoff, as a fraction (e.g. `0.2` = +/-20%)
+        jitter: f64,
+    },
+    /// Never attempt automatic recovery; the application must handle it
+    Fail,
+}
+
+impl ReconnectStrategy {
+    fn delay_for(&self, attempt: u32) -> Option<Duration> {
+        match *self {
+            ReconnectStrategy::FixedInterval(interval) => Some(interval),
+            ReconnectStrategy::ExponentialBackoff { base, factor, max, jitter } => {
+                let scaled = base.as_secs_f64() * factor.powi(attempt as i32);
+                let capped = scaled.min(max.as_secs_f64());
+                let spread = capped * jitter * (pseudo_unit_random() * 2.0 - 1.0);
+                Some(Duration::from_secs_f64((capped + spread).max(0.0)))
+            }
+            ReconnectStrategy::Fail => None,
+        }
+    }
+}
+
+/// A recovery notification emitted by [`Supervisor::observe`].
+///
+/// This is deliberately kept separate from [`EventType`](crate::EventType):
+/// it describes the supervisor's own actions, not a raw NORM event.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SupervisorEvent {
+    /// The supervisor is re-arming a stale node's receiver
+    Reconnecting {
+        /// The node being recovered
+        node: NodeId,
+        /// The 1-based retry attempt number
+        attempt: u32,
+    },
+    /// The supervisor gave up on a node per its [`ReconnectStrategy`]
+    GivenUp {
+        /// The node that was abandoned
+        node: NodeId,
+    },
+    /// The session's local sender closed; every tracked node was dropped
+    /// since there's nothing left to reconnect on behalf of
+    Closed,
+}
+
+#[derive(Debug)]
+struct TrackedNode {
+    attempt: u32,
+    stale: bool,
+    /// When a stale node becomes due for its next reconnect attempt, per
+    /// the configured [`ReconnectStrategy`]'s backoff. `None` once the node
+    /// isn't awaiting a retry (fresh, or already re-armed).
+    next_attempt_at: Option<Instant>,
+}
+
+/// Tracks remote-sender liveness on a [`Session`] and drives automatic recovery.
+///
+/// Feed every event from the owning session's event loop into
+/// [`Supervisor::observe`]; call [`Supervisor::maybe_keepalive`] and
+/// [`Supervisor::poll_reconnects`] periodically (e.g. both on the same timer
+/// tick) so an otherwise-idle session doesn't get flagged as inactive by its
+/// peers, and a stale node is re-armed once its backoff delay actually
+/// elapses rather than instantly.
+pub struct Supervisor<'s> {
+    session: &'s Session,
+    strategy: ReconnectStrategy,
+    nodes: HashMap<NodeId, TrackedNode>,
+    keepalive_interval: Duration,
+    last_keepalive: Instant,
+}
+
+impl<'s> Supervisor<'s> {
+    /// Create a supervisor for `session` using the given recovery strategy
+    pub fn new(session: &'s Session, strategy: ReconnectStrategy) -> Self {
+        Supervisor {
+            session,
+            strategy,
+            nodes: HashMap::new(),
+            keepalive_interval: Duration::from_secs(5),
+            last_keepalive: Instant::now(),
+        }
+    }
+
+    /// Set how often [`Supervisor::maybe_keepalive`] sends idle-keepalive activity
+    pub fn keepalive_interval(mut self, interval: Duration) -> Self {
+        self.keepalive_interval = interval;
+        self
+    }
+
+    /// Feed an event from the session's event loop into the supervisor
+    ///
+    /// # Returns
+    /// `Some(SupervisorEvent)` when the supervisor took, or gave up on, a
+    /// recovery action worth surfacing to the application
+    pub fn observe(&mut self, event: &Event) -> Option<SupervisorEvent> {
+        let node_id = Node::from_handle_unowned(event.node).id();
+
+        match event.event_type {
+            EventType::RemoteSenderNew | EventType::RemoteSenderActive => {
+                self.nodes.insert(node_id, TrackedNode { attempt: 0, stale: false, next_attempt_at: None });
+                None
+            }
+            EventType::RemoteSenderInactive | EventType::RemoteSenderPurged => {
+                self.nodes
+                    .entry(node_id)
+                    .or_insert_with(|| TrackedNode { attempt: 0, stale: false, next_attempt_at: None })
+                    .stale = true;
+                self.try_reconnect(node_id)
+            }
+            EventType::LocalSenderClosed => {
+                // Our own sender is gone, so there's nothing left to recover
+                // on behalf of -- drop every tracked node rather than leaving
+                // them to be re-armed against a sender that no longer exists.
+                self.nodes.clear();
+                Some(SupervisorEvent::Closed)
+            }
+            _ => None,
+        }
+    }
+
+    /// Schedule a stale node's next reconnect attempt per the configured
+    /// backoff, rather than re-arming it immediately.
+    ///
+    /// # Returns
+    /// `Some(SupervisorEvent::GivenUp)` if the strategy has exhausted its
+    /// retries for this node; otherwise `None` -- the actual
+    /// `Reconnecting` event is emitted later by [`Supervisor::poll_reconnects`]
+    /// once the scheduled delay elapses.
+    fn try_reconnect(&mut self, node_id: NodeId) -> Option<SupervisorEvent> {
+        let tracked = self.nodes.get_mut(&node_id)?;
+        if !tracked.stale {
+            return None;
+        }
+
+        match self.strategy.delay_for(tracked.attempt) {
+            Some(delay) => {
+                tracked.next_attempt_at = Some(Instant::now() + delay);
+                None
+            }
+            None => Some(SupervisorEvent::GivenUp { node: node_id }),
+        }
+    }
+
+    /// Re-arm any stale nodes whose scheduled backoff delay has elapsed.
+    ///
+    /// Call this periodically (e.g. alongside [`Supervisor::maybe_keepalive`]
+    /// on the same timer tick); a node marked stale by [`Supervisor::observe`]
+    /// is only actually re-armed once this reaches its `next_attempt_at`,
+    /// not the instant it went stale -- that delay, with jitter, is the
+    /// whole point of a [`ReconnectStrategy`] in the first place.
+    ///
+    /// # Returns
+    /// One [`SupervisorEvent::Reconnecting`] per node whose retry fired this call.
+    pub fn poll_reconnects(&mut self) -> Vec<SupervisorEvent> {
+        let now = Instant::now();
+        let mut due = Vec::new();
+
+        for (&node_id, tracked) in self.nodes.iter_mut() {
+            if !tracked.stale {
+                continue;
+            }
+            match tracked.next_attempt_at {
+                Some(at) if now >= at => {
+                    tracked.attempt += 1;
+                    tracked.stale = false;
+                    tracked.next_attempt_at = None;
+                    due.push((node_id, tracked.attempt));
+                }
+                _ => {}
+            }
+        }
+
+        due.into_iter()
+            .map(|(node_id, attempt)| {
+                // The concrete recovery action available to us is re-arming
+                // our own receiver; NORM resynchronizes with the remote
+                // sender passively once traffic resumes.
+                let _ = self.session.start_receiver(1024 * 1024);
+                SupervisorEvent::Reconnecting { node: node_id, attempt }
+            })
+            .collect()
+    }
+
+    /// Send idle-keepalive activity if the configured interval has elapsed
+    ///
+    /// # Errors
+    /// Returns an error if the keepalive command could not be sent
+    pub fn maybe_keepalive(&mut self) -> Result<()> {
+        if self.last_keepalive.elapsed() < self.keepalive_interval {
+            return Ok(());
+        }
+
+        self.last_keepalive = Instant::now();
+        self.session.send_command(b"\0", false)
+    }
+}
+
+/// A unit-interval pseudo-random value, used only for backoff jitter
+fn pseudo_unit_random() -> f64 {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    let bits = RandomState::new().build_hasher().finish();
+    (bits as f64) / (u64::MAX as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixed_interval_delay() {
+        let strategy = ReconnectStrategy::FixedInterval(Duration::from_secs(3));
+        assert_eq!(strategy.delay_for(0), Some(Duration::from_secs(3)));
+        assert_eq!(strategy.delay_for(10), Some(Duration::from_secs(3)));
+    }
+
+    #[test]
+    fn test_fail_strategy_never_delays() {
+        let strategy = ReconnectStrategy::Fail;
+        assert_eq!(strategy.delay_for(0), None);
+        assert_eq!(strategy.delay_for(5), None);
+    }
+
+    #[test]
+    fn test_exponential_backoff_is_capped_at_max() {
+        let strategy = ReconnectStrategy::ExponentialBackoff {
+            base: Duration::from_secs(1),
+            factor: 2.0,
+            max: Duration::from_secs(10),
+            jitter: 0.0,
+        };
+
+        // With zero jitter the result is deterministic: base * factor^attempt,
+        // capped at `max`.
+        assert_eq!(strategy.delay_for(0), Some(Duration::from_secs(1)));
+        assert_eq!(strategy.delay_for(1), Some(Duration::from_secs(2)));
+        assert_eq!(strategy.delay_for(2), Some(Duration::from_secs(4)));
+        assert_eq!(strategy.delay_for(10), Some(Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn test_exponential_backoff_jitter_stays_within_bounds() {
+        let strategy = ReconnectStrategy::ExponentialBackoff {
+            base: Duration::from_secs(4),
+            factor: 1.0,
+            max: Duration::from_secs(100),
+            jitter: 0.2,
+        };
+
+        for attempt in 0..20 {
+            let delay = strategy.delay_for(attempt).unwrap().as_secs_f64();
+            assert!(delay >= 4.0 * 0.8 - f64::EPSILON, "delay {delay} below jitter floor");
+            assert!(delay <= 4.0 * 1.2 + f64::EPSILON, "delay {delay} above jitter ceiling");
+        }
+    }
+}
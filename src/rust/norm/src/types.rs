@@ -14,6 +14,10 @@ pub type SessionId = u16;
 /// NORM object transport identifier type
 pub type ObjectTransportId = u16;
 
+/// Identifies one in-flight call in the [`crate::rpc`] request/response layer
+#[cfg(feature = "tokio")]
+pub type RequestId = u64;
+
 /// NORM size type for file and object sizes
 #[cfg(unix)]
 pub type Size = i64;
@@ -24,6 +28,7 @@ pub type Size = i64;
 
 /// NORM object types
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum ObjectType {
     /// Placeholder for no object type
@@ -61,6 +66,7 @@ impl From<ObjectType> for NormObjectType {
 
 /// NORM flush modes
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum FlushMode {
     /// No flush
@@ -94,6 +100,7 @@ impl From<FlushMode> for NormFlushMode {
 
 /// NORM nacking modes
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum NackingMode {
     /// No NACKs
@@ -127,6 +134,7 @@ impl From<NackingMode> for NormNackingMode {
 
 /// NORM acking status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum AckingStatus {
     /// Invalid ack status
@@ -164,6 +172,7 @@ impl From<AckingStatus> for NormAckingStatus {
 
 /// NORM tracking status
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum TrackingStatus {
     /// No tracking
@@ -201,6 +210,7 @@ impl From<TrackingStatus> for NormTrackingStatus {
 
 /// NORM probing mode
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum ProbingMode {
     /// No probing
@@ -234,6 +244,7 @@ impl From<ProbingMode> for NormProbingMode {
 
 /// NORM sync policy
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum SyncPolicy {
     /// Sync to current data (join mid-stream)
@@ -267,6 +278,7 @@ impl From<SyncPolicy> for NormSyncPolicy {
 
 /// NORM repair boundary
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum RepairBoundary {
     /// Block boundary
@@ -294,6 +306,32 @@ impl From<RepairBoundary> for NormRepairBoundary {
     }
 }
 
+/// NORM FEC (Forward Error Correction) codec selection
+///
+/// Passed as the `fec_id` argument to `NormStartSender`. `ReedSolomon8` is
+/// NORM's default and handles general block/parity ratios; `SlidingWindow`
+/// trades flexibility for much lower computational overhead and is only
+/// suitable for small parity counts (historically `num_parity == 1`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FecType {
+    /// 8-bit Reed-Solomon coding (the NORM default)
+    ReedSolomon8,
+    /// 16-bit Reed-Solomon coding, for larger block sizes or parity counts
+    ReedSolomon16,
+    /// Low-overhead sliding-window (XOR) parity coding
+    SlidingWindow,
+}
+
+impl From<FecType> for u8 {
+    fn from(t: FecType) -> u8 {
+        match t {
+            FecType::ReedSolomon8 => 0,
+            FecType::SlidingWindow => 2,
+            FecType::ReedSolomon16 => 5,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -353,6 +391,7 @@ mod tests {
 
 /// NORM event type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(u32)]
 pub enum EventType {
     /// Invalid event